@@ -0,0 +1,62 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use cu::units::Unit;
+use cu::UnitRegistry;
+
+// unit lookups of each kind: an exact abbreviation, a case-insensitive alias,
+// an exact prefixed unit, a prefixed unit name and an unknown unit
+const LOOKUPS: [(&str, &str); 5] = [
+    ("exact", "ft"),
+    ("alias", "Fluid Ounces"),
+    ("prefixed", "kPa"),
+    ("prefixed name", "kilopascals"),
+    ("unknown", "furlongs per fortnight"),
+];
+
+// the baseline: a scan of the units of every unit type, lowercasing the unit string for every unit type
+// (the lookup before the index, without the prefixed units)
+fn scan_unit<'a>(registry: &'a UnitRegistry, unit_str: &str) -> Option<(&'a str, &'a Unit)> {
+    for (unit_type, units) in registry.unit_types() {
+        if let Some(unit) = units.iter().find(|u| u.abbr == unit_str) {
+            return Some((unit_type, unit));
+        }
+        let unit_lc = unit_str.to_lowercase();
+        if let Some(unit) = units.iter().find(|u| {
+            u.abbr.to_lowercase() == unit_lc
+                || u.aliases.iter().any(|a| a.to_lowercase() == unit_lc)
+        }) {
+            return Some((unit_type, unit));
+        }
+    }
+    None
+}
+
+// the indexed lookup compared with the baseline scan
+fn find_unit(c: &mut Criterion) {
+    let registry = UnitRegistry::default();
+    let mut group = c.benchmark_group("find_unit");
+    for (name, unit_str) in LOOKUPS {
+        group.bench_with_input(BenchmarkId::new("index", name), unit_str, |b, unit_str| {
+            b.iter(|| registry.find_unit(black_box(unit_str), None))
+        });
+        group.bench_with_input(BenchmarkId::new("scan", name), unit_str, |b, unit_str| {
+            b.iter(|| scan_unit(&registry, black_box(unit_str)))
+        });
+    }
+    group.finish();
+}
+
+fn convert(c: &mut Criterion) {
+    let registry = UnitRegistry::default();
+    let mut group = c.benchmark_group("convert");
+    group.bench_function("simple", |b| {
+        b.iter(|| registry.convert(black_box("5 ft 10 in to m")))
+    });
+    group.bench_function("derived", |b| {
+        b.iter(|| registry.convert(black_box("60 mi/hr to m/s")))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, find_unit, convert);
+criterion_main!(benches);
@@ -0,0 +1,62 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+
+use cu::output::{OutputFormat, TSV_HEADER};
+use cu::UnitRegistry;
+
+// run the conversions of a file (or of stdin), one per line, and return whether all of them succeeded:
+// the results (in the given output format) go to stdout, the errors and warnings (prefixed with the line number) go to stderr,
+// empty lines and lines starting with "#" are skipped
+pub fn run(registry: &UnitRegistry, path: Option<&str>, format: OutputFormat) -> bool {
+    let reader: Box<dyn BufRead> = match path {
+        Some(path) => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(e) => {
+                eprintln!("[ Can't read the file {}: {} ]", path, e);
+                return false;
+            }
+        },
+        None => Box::new(BufReader::new(io::stdin())),
+    };
+    let mut stdout = BufWriter::new(io::stdout().lock());
+    let mut stderr = io::stderr().lock();
+    let mut success = true;
+    if format == OutputFormat::Tsv {
+        writeln!(stdout, "{}", TSV_HEADER).unwrap();
+    }
+    for (i, line) in reader.lines().enumerate() {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                writeln!(stderr, "line {}: [ Can't read the line: {} ]", i + 1, e).unwrap();
+                success = false;
+                break;
+            }
+        };
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match registry.convert(line) {
+            Ok(conversion) => {
+                if !conversion.warnings.is_empty() {
+                    stdout.flush().unwrap();
+                }
+                for warning in conversion.warnings.iter() {
+                    writeln!(stderr, "line {}: {}", i + 1, warning).unwrap();
+                }
+                for record in conversion.records(format) {
+                    writeln!(stdout, "{}", record).unwrap();
+                }
+            }
+            Err(e) => {
+                // (keep the results and the errors in order when both go to the same terminal)
+                stdout.flush().unwrap();
+                writeln!(stderr, "line {}: {}", i + 1, e).unwrap();
+                success = false;
+            }
+        }
+    }
+    stdout.flush().unwrap();
+    success
+}
@@ -0,0 +1,290 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::dimension::Dimension;
+use crate::error::ConvertError;
+use crate::registry::UnitRegistry;
+use crate::units::{Prefixes, Scale, Unit};
+
+// a units file, e.g.:
+//
+// [[types]]
+// name = "THROUGHPUT"
+// dimension = "B/s"
+//
+// [[units]]
+// type = "LENGTH"
+// name = "Rack Unit"
+// abbr = "U"
+// aliases = ["rack unit", "rack units"]
+// ratio = 0.04445
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct UnitsFile {
+    #[serde(default)]
+    types: Vec<TypeDefinition>,
+    #[serde(default)]
+    units: Vec<UnitDefinition>,
+}
+
+// a new unit type: either of the dimension of a unit (e.g. "B/s") or a new base dimension (e.g. "STORY POINTS")
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TypeDefinition {
+    name: String,
+    dimension: Option<String>,
+}
+
+// a unit of a built-in or new unit type: the ratios are relative to the coherent SI unit of the dimension
+// (or to the unit with a ratio of 1 for a new base dimension)
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct UnitDefinition {
+    #[serde(rename = "type")]
+    unit_type: String,
+    name: String,
+    abbr: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+    ratio: Option<f64>,
+    // the ratio variants, e.g. [["Imperial", 1.0], ["US", 0.9]]
+    ratios: Option<Vec<(String, f64)>>,
+    #[serde(default)]
+    offset: f64,
+    // a unit of the inverse of the dimension (e.g. a pace for a speed)
+    #[serde(default)]
+    inverse: bool,
+}
+
+// the default units file: $XDG_CONFIG_HOME/cu/units.toml (or ~/.config/cu/units.toml)
+pub fn default_units_file() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|d| !d.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))?;
+    Some(config_dir.join("cu").join("units.toml"))
+}
+
+impl UnitRegistry {
+    // load the units of a units file and add them to the registry,
+    // returning the units that were skipped because of a conflict with a known unit
+    pub fn load_units_file(&mut self, path: &Path) -> Result<Vec<ConvertError>, ConvertError> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            ConvertError::InvalidUnitsFile(format!("can't read {}: {}", path.display(), e))
+        })?;
+        self.load_units(&content).map_err(|e| match e {
+            ConvertError::InvalidUnitsFile(e) => {
+                ConvertError::InvalidUnitsFile(format!("{}: {}", path.display(), e))
+            }
+            e => e,
+        })
+    }
+
+    // load the units of a units file content (see UnitsFile) and add them to the registry,
+    // returning the units that were skipped because of a conflict with a known unit
+    // (the whole file is checked first: the registry is left as it was if the file is invalid)
+    pub fn load_units(&mut self, content: &str) -> Result<Vec<ConvertError>, ConvertError> {
+        let file: UnitsFile = toml::from_str(content).map_err(|e| {
+            let line = e
+                .span()
+                .map(|span| format!("line {}: ", content[..span.start].matches('\n').count() + 1))
+                .unwrap_or_default();
+            ConvertError::InvalidUnitsFile(format!("{}{}", line, e.message()))
+        })?;
+
+        // the new unit types with their dimensions, the ones of a new base dimension,
+        // and the unit types they are equivalent to
+        let mut new_types: Vec<(String, Dimension)> = Vec::new();
+        let mut new_base_types: Vec<String> = Vec::new();
+        let mut equivalent_types: Vec<(String, String)> = Vec::new();
+        for type_definition in file.types.iter() {
+            let name = type_definition.name.trim().to_uppercase();
+            if name.is_empty() {
+                return Err(ConvertError::InvalidUnitsFile(
+                    "a unit type without a name".to_string(),
+                ));
+            }
+            if self
+                .unit_types()
+                .any(|(t, _)| t.eq_ignore_ascii_case(&name))
+                || new_types.iter().any(|(t, _)| *t == name)
+            {
+                return Err(ConvertError::InvalidUnitsFile(format!(
+                    "the unit type {} already exists",
+                    name
+                )));
+            }
+            let dimension = match type_definition.dimension.as_deref() {
+                Some(unit_str) => {
+                    let unit = self.resolve_unit(unit_str, None).map_err(|_| {
+                        ConvertError::InvalidUnitsFile(format!(
+                            "unknown unit '{}' in the dimension of {}",
+                            unit_str, name
+                        ))
+                    })?;
+                    // (the unit type of the unit, e.g. DATA RATE for "B/s", converts into the new one)
+                    if let Some(unit_type) = unit
+                        .unit_type
+                        .as_deref()
+                        .or_else(|| self.find_unit_type(&unit.dimension))
+                    {
+                        equivalent_types.push((name.clone(), unit_type.to_string()));
+                    }
+                    unit.dimension
+                }
+                None => self.new_base_dimension(&name, &mut new_base_types)?,
+            };
+            new_types.push((name, dimension));
+        }
+
+        let mut conflicts = Vec::new();
+        let mut units: Vec<(String, Unit)> = Vec::new();
+        for definition in file.units.iter() {
+            let unit_type_name = definition.unit_type.trim().to_uppercase();
+            // a known unit type, a new one, or a new base dimension for an undeclared one
+            let known_type = self
+                .unit_types()
+                .find(|(t, _)| t.eq_ignore_ascii_case(&unit_type_name))
+                .and_then(|(t, units)| units.first().map(|u| (t.to_string(), u.dimension)));
+            let (unit_type, dimension) = match known_type.or_else(|| {
+                new_types
+                    .iter()
+                    .find(|(t, _)| *t == unit_type_name)
+                    .cloned()
+            }) {
+                Some(unit_type) => unit_type,
+                None => {
+                    let dimension =
+                        self.new_base_dimension(&unit_type_name, &mut new_base_types)?;
+                    new_types.push((unit_type_name.clone(), dimension));
+                    (unit_type_name, dimension)
+                }
+            };
+            let unit = parse_unit(definition, dimension)?;
+
+            // the abbreviation and the aliases must not match a known unit (or another unit of the file)
+            let conflict = std::iter::once(&unit.abbr)
+                .chain(unit.aliases.iter())
+                .find_map(|symbol| {
+                    let existing = self
+                        .find_units(symbol, None)
+                        .first()
+                        .map(|u| format!("{} ({})", u.name, u.describe(self)))
+                        .or_else(|| {
+                            units
+                                .iter()
+                                .find(|(_, u)| {
+                                    std::iter::once(&u.abbr)
+                                        .chain(u.aliases.iter())
+                                        .any(|s| s.eq_ignore_ascii_case(symbol))
+                                })
+                                .map(|(t, u)| format!("{} ({})", u.name, t))
+                        })?;
+                    Some(ConvertError::UnitConflict {
+                        unit: unit.name.clone(),
+                        symbol: symbol.clone(),
+                        existing,
+                    })
+                });
+            match conflict {
+                Some(conflict) => conflicts.push(conflict),
+                None => units.push((unit_type, unit)),
+            }
+        }
+        // a new unit type needs units (a unit type is only known by its units)
+        let file_unit_types: Vec<String> = file
+            .units
+            .iter()
+            .map(|u| u.unit_type.trim().to_uppercase())
+            .collect();
+        if let Some(name) = file
+            .types
+            .iter()
+            .map(|t| t.name.trim().to_uppercase())
+            .find(|name| !file_unit_types.contains(name))
+        {
+            return Err(ConvertError::InvalidUnitsFile(format!(
+                "the unit type {} has no units",
+                name
+            )));
+        }
+
+        for unit_type in new_base_types {
+            self.add_custom_dimension(&unit_type);
+        }
+        for (unit_type, unit) in units {
+            self.add_unit(&unit_type, unit);
+        }
+        for (unit_type, equivalent_type) in equivalent_types {
+            self.add_equivalent_type(&unit_type, &equivalent_type);
+        }
+        Ok(conflicts)
+    }
+
+    // the dimension of a new base unit type (added to the registry with the rest of the file)
+    fn new_base_dimension(
+        &self,
+        unit_type: &str,
+        new_base_types: &mut Vec<String>,
+    ) -> Result<Dimension, ConvertError> {
+        let dimension = self
+            .custom_dimension(unit_type, new_base_types)
+            .ok_or_else(|| {
+                ConvertError::InvalidUnitsFile(format!(
+                    "too many new base unit types (the unit type {} needs a dimension)",
+                    unit_type
+                ))
+            })?;
+        new_base_types.push(unit_type.to_string());
+        Ok(dimension)
+    }
+}
+
+fn parse_unit(definition: &UnitDefinition, dimension: Dimension) -> Result<Unit, ConvertError> {
+    let invalid = |e: &str| {
+        ConvertError::InvalidUnitsFile(format!("the unit {} {}", definition.name.trim(), e))
+    };
+    if definition.name.trim().is_empty() || definition.abbr.trim().is_empty() {
+        return Err(ConvertError::InvalidUnitsFile(
+            "a unit without a name or an abbreviation".to_string(),
+        ));
+    }
+    let ratios: Vec<(String, f64)> = match (definition.ratio, definition.ratios.as_ref()) {
+        (Some(ratio), None) => vec![("".to_string(), ratio)],
+        (None, Some(ratios)) if !ratios.is_empty() => ratios
+            .iter()
+            .map(|(label, ratio)| (label.trim().to_string(), *ratio))
+            .collect(),
+        (Some(_), Some(_)) => return Err(invalid("has both a ratio and ratios")),
+        _ => return Err(invalid("has no ratio")),
+    };
+    if ratios.iter().any(|(_, r)| !r.is_finite() || *r <= 0.0) {
+        return Err(invalid("has a ratio that is not a positive number"));
+    }
+    if !definition.offset.is_finite() {
+        return Err(invalid("has an offset that is not a number"));
+    }
+    Ok(Unit {
+        name: definition.name.trim().to_string(),
+        abbr: definition.abbr.trim().to_string(),
+        aliases: definition
+            .aliases
+            .iter()
+            .map(|a| a.trim().to_string())
+            .filter(|a| !a.is_empty())
+            .collect(),
+        dimension,
+        ratios: ratios
+            .into_iter()
+            .map(|(label, ratio)| (label, ratio.into()))
+            .collect(),
+        offset: definition.offset.into(),
+        scale: if definition.inverse {
+            Scale::Inverse
+        } else {
+            Scale::Linear
+        },
+        prefixes: Prefixes::None,
+    })
+}
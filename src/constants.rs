@@ -0,0 +1,330 @@
+// the defining constants of the built-in units, in the coherent SI units (exact unless noted otherwise)
+//
+// sources:
+// - the SI Brochure, 9th edition (BIPM, 2019), for the defining constants of the SI
+// - NIST Special Publication 811 (2008), appendix B, for the US customary and other non-SI units
+// - NIST Handbook 44 (2024), appendix C, for the US customary volumes
+// - the UK Weights and Measures Act 1985, schedule 1, for the imperial units
+// - the IAU 2012 Resolution B2 and 2015 Resolution B2 for the astronomical units
+
+use std::ops::{Add, Div, Mul, Sub};
+
+// the definition of a unit ratio or offset: its exact value, a quotient of decimal numbers
+// (e.g. 1200/3937 for the US survey foot), with the floating point value of the same expression
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Definition {
+    pub value: f64,
+    // the exact value is numerator / denominator * 10^exponent, in lowest terms
+    // (a denominator of 0 if there is none, e.g. for an infinite value)
+    numerator: i128,
+    denominator: i128,
+    exponent: i32,
+}
+
+// the definition of a decimal literal, e.g. decimal!(0.3048) (its exact value is read from the literal)
+macro_rules! decimal {
+    ($value:literal) => {
+        Definition::decimal($value, stringify!($value))
+    };
+}
+
+impl Definition {
+    pub const ZERO: Definition = Definition::new(0.0, 0, 1, 0);
+
+    const fn new(value: f64, numerator: i128, denominator: i128, exponent: i32) -> Definition {
+        if denominator == 0 {
+            return Definition {
+                value,
+                numerator: 0,
+                denominator: 0,
+                exponent: 0,
+            };
+        }
+        if numerator == 0 {
+            return Definition {
+                value,
+                numerator: 0,
+                denominator: 1,
+                exponent: 0,
+            };
+        }
+        let (mut numerator, mut denominator, mut exponent) = if denominator < 0 {
+            (-numerator, -denominator, exponent)
+        } else {
+            (numerator, denominator, exponent)
+        };
+        let gcd = gcd(numerator.abs(), denominator);
+        numerator /= gcd;
+        denominator /= gcd;
+        while numerator % 10 == 0 {
+            numerator /= 10;
+            exponent += 1;
+        }
+        while denominator % 10 == 0 {
+            denominator /= 10;
+            exponent -= 1;
+        }
+        Definition {
+            value,
+            numerator,
+            denominator,
+            exponent,
+        }
+    }
+
+    // the definition of a decimal literal with its value (see decimal!), e.g. "1_609.344" or "1.5e-9"
+    pub const fn decimal(value: f64, literal: &str) -> Definition {
+        let bytes = literal.as_bytes();
+        let (mut digits, mut exponent, mut fraction, mut i) = (0i128, 0i32, false, 0);
+        while i < bytes.len() {
+            match bytes[i] {
+                b'0'..=b'9' => {
+                    digits = checked(digits.checked_mul(10)) + (bytes[i] - b'0') as i128;
+                    if fraction {
+                        exponent -= 1;
+                    }
+                }
+                b'.' => fraction = true,
+                b'_' => {}
+                b'e' | b'E' => {
+                    let (negative, mut power) = (bytes[i + 1] == b'-', 0);
+                    i += 1;
+                    while i < bytes.len() {
+                        if bytes[i].is_ascii_digit() {
+                            power = power * 10 + (bytes[i] - b'0') as i32;
+                        }
+                        i += 1;
+                    }
+                    exponent += if negative { -power } else { power };
+                }
+                _ => panic!("not a decimal literal"),
+            }
+            i += 1;
+        }
+        Definition::new(value, digits, 1, exponent)
+    }
+
+    pub const fn times(self, other: Definition) -> Definition {
+        if self.denominator == 0 || other.denominator == 0 {
+            return Definition::new(self.value * other.value, 0, 0, 0);
+        }
+        Definition::new(
+            self.value * other.value,
+            checked(self.numerator.checked_mul(other.numerator)),
+            checked(self.denominator.checked_mul(other.denominator)),
+            self.exponent + other.exponent,
+        )
+    }
+
+    pub const fn divided_by(self, other: Definition) -> Definition {
+        if self.denominator == 0 || other.denominator == 0 || other.numerator == 0 {
+            return Definition::new(self.value / other.value, 0, 0, 0);
+        }
+        Definition::new(
+            self.value / other.value,
+            checked(self.numerator.checked_mul(other.denominator)),
+            checked(self.denominator.checked_mul(other.numerator)),
+            self.exponent - other.exponent,
+        )
+    }
+
+    pub const fn plus(self, other: Definition) -> Definition {
+        if self.denominator == 0 || other.denominator == 0 {
+            return Definition::new(self.value + other.value, 0, 0, 0);
+        }
+        // (with the smaller exponent of the two)
+        let exponent = if self.exponent < other.exponent {
+            self.exponent
+        } else {
+            other.exponent
+        };
+        let numerator = checked(
+            scaled(self.numerator, self.exponent - exponent).checked_mul(other.denominator),
+        );
+        let other_numerator = checked(
+            scaled(other.numerator, other.exponent - exponent).checked_mul(self.denominator),
+        );
+        Definition::new(
+            self.value + other.value,
+            checked(numerator.checked_add(other_numerator)),
+            checked(self.denominator.checked_mul(other.denominator)),
+            exponent,
+        )
+    }
+
+    pub const fn minus(self, other: Definition) -> Definition {
+        let negated = Definition {
+            value: -other.value,
+            numerator: -other.numerator,
+            ..other
+        };
+        Definition {
+            value: self.value - other.value,
+            ..self.plus(negated)
+        }
+    }
+
+    // the exact value as (numerator, denominator, exponent of 10), or None if there is none
+    #[cfg(feature = "exact")]
+    pub(crate) fn parts(&self) -> Option<(i128, i128, i32)> {
+        (self.denominator != 0).then_some((self.numerator, self.denominator, self.exponent))
+    }
+}
+
+// the greatest common divisor of two positive numbers
+const fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a, b);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// a number times a power of 10
+const fn scaled(value: i128, exponent: i32) -> i128 {
+    let (mut value, mut exponent) = (value, exponent);
+    while exponent > 0 {
+        value = checked(value.checked_mul(10));
+        exponent -= 1;
+    }
+    value
+}
+
+// the result of a checked operation of the exact values (the built-in definitions fit an i128)
+const fn checked(result: Option<i128>) -> i128 {
+    match result {
+        Some(value) => value,
+        None => panic!("the exact value of a definition overflows"),
+    }
+}
+
+// the shortest decimal number that rounds to the value (e.g. 0.3048 rather than the binary fraction
+// closest to it), or the value itself for an integer with more digits (e.g. 2^60 for the exbi- prefix)
+impl From<f64> for Definition {
+    fn from(value: f64) -> Definition {
+        if !value.is_finite() {
+            return Definition::new(value, 0, 0, 0);
+        }
+        // (the LowerExp format is the shortest representation that round-trips, e.g. "3.048e-1")
+        let formatted = format!("{:e}", value);
+        let (mantissa, exponent) = formatted.split_once('e').unwrap();
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let digits = format!("{}{}", integer, fraction);
+        if value.fract() == 0.0 && fraction.len() >= 15 && value.abs() < i128::MAX as f64 {
+            return Definition::new(value, value as i128, 1, 0);
+        }
+        Definition::new(
+            value,
+            digits.parse().unwrap(),
+            1,
+            exponent.parse::<i32>().unwrap() - fraction.len() as i32,
+        )
+    }
+}
+
+impl Mul for Definition {
+    type Output = Definition;
+
+    fn mul(self, other: Definition) -> Definition {
+        self.times(other)
+    }
+}
+
+impl Mul<f64> for Definition {
+    type Output = Definition;
+
+    fn mul(self, other: f64) -> Definition {
+        self.times(other.into())
+    }
+}
+
+impl Mul<Definition> for f64 {
+    type Output = Definition;
+
+    fn mul(self, other: Definition) -> Definition {
+        Definition::from(self).times(other)
+    }
+}
+
+impl Div for Definition {
+    type Output = Definition;
+
+    fn div(self, other: Definition) -> Definition {
+        self.divided_by(other)
+    }
+}
+
+impl Div<f64> for Definition {
+    type Output = Definition;
+
+    fn div(self, other: f64) -> Definition {
+        self.divided_by(other.into())
+    }
+}
+
+impl Add for Definition {
+    type Output = Definition;
+
+    fn add(self, other: Definition) -> Definition {
+        self.plus(other)
+    }
+}
+
+impl Sub for Definition {
+    type Output = Definition;
+
+    fn sub(self, other: Definition) -> Definition {
+        self.minus(other)
+    }
+}
+
+// π, with the precision of a f64 (the ratios of the angle units and the parsec are irrational)
+pub const PI: Definition = Definition::decimal(std::f64::consts::PI, "3.141592653589793");
+
+// the speed of light in vacuum (SI Brochure, defining constant c)
+pub const SPEED_OF_LIGHT: Definition = decimal!(299_792_458.0);
+// the elementary charge (SI Brochure, defining constant e)
+pub const ELEMENTARY_CHARGE: Definition = decimal!(1.602_176_634e-19);
+// the standard acceleration of gravity (3rd CGPM, 1901)
+pub const STANDARD_GRAVITY: Definition = decimal!(9.806_65);
+
+// the international yard and pound (international yard and pound agreement of 1959, NIST SP 811 B.8),
+// with the inch (1/36 yd), the foot (1/3 yd) and the mile (1760 yd) written out
+// (their products of floating point numbers would be off by a rounding error)
+pub const INCH: Definition = decimal!(0.0254);
+pub const FOOT: Definition = decimal!(0.3048);
+pub const YARD: Definition = decimal!(0.9144);
+pub const MILE: Definition = decimal!(1_609.344);
+pub const POUND: Definition = decimal!(0.453_592_37);
+// the US survey foot (US Metric Act of 1866 and Mendenhall Order of 1893, deprecated since 2023)
+pub const US_SURVEY_FOOT: Definition = decimal!(1200.0).divided_by(decimal!(3937.0));
+// the international nautical mile (International Hydrographic Conference of 1929, NIST SP 811 B.8)
+pub const NAUTICAL_MILE: Definition = decimal!(1852.0);
+// the astronomical unit (IAU 2012 Resolution B2)
+pub const ASTRONOMICAL_UNIT: Definition = decimal!(149_597_870_700.0);
+// the Julian year of the light-year (IAU, 365.25 days of 86 400 s)
+pub const JULIAN_YEAR: Definition = decimal!(365.25).times(DAY);
+// the parsec (IAU 2015 Resolution B2: 648 000/π au)
+pub const PARSEC: Definition = decimal!(648_000.0).divided_by(PI).times(ASTRONOMICAL_UNIT);
+// the Planck length (not exact: the CODATA 2018 recommended value, relative uncertainty 1.1e-5)
+pub const PLANCK_LENGTH: Definition = decimal!(1.616_255e-35);
+
+// the day and the mean Gregorian year (365.2425 days)
+pub const DAY: Definition = decimal!(86_400.0);
+pub const GREGORIAN_YEAR: Definition = decimal!(365.2425).times(DAY);
+
+// the imperial gallon (Weights and Measures Act 1985) and the US gallon (231 in³, NIST Handbook 44)
+pub const IMPERIAL_GALLON: Definition = decimal!(4.546_09e-3);
+pub const US_GALLON: Definition = decimal!(231.0).times(INCH).times(INCH).times(INCH);
+// the imperial and US fluid ounces (1/160 and 1/128 gallon)
+pub const IMPERIAL_FLUID_OUNCE: Definition = IMPERIAL_GALLON.divided_by(decimal!(160.0));
+pub const US_FLUID_OUNCE: Definition = US_GALLON.divided_by(decimal!(128.0));
+
+// the pound-force (NIST SP 811 B.8)
+pub const POUND_FORCE: Definition = POUND.times(STANDARD_GRAVITY);
+// the standard atmosphere (10th CGPM, 1954)
+pub const STANDARD_ATMOSPHERE: Definition = decimal!(101_325.0);
+// the thermochemical calorie (NIST SP 811 B.8)
+pub const CALORIE: Definition = decimal!(4.184);
+// the International Table British thermal unit (NIST SP 811 B.8)
+pub const BTU: Definition = decimal!(1_055.055_852_62);
@@ -0,0 +1,419 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::constants::Definition;
+#[cfg(feature = "exact")]
+use crate::exact::{self, Rational};
+use crate::registry::UnitRegistry;
+use crate::units::{Prefix, Scale, Unit};
+#[cfg(feature = "exact")]
+use num_traits::{One, Zero};
+
+// the number of base dimensions
+pub const BASE_DIMENSIONS: usize = 8;
+
+// the symbols of the base dimensions:
+// length, mass, time, electric current, temperature, amount of substance, luminous intensity and information
+const BASE_DIMENSION_SYMBOLS: [&str; BASE_DIMENSIONS] = ["L", "M", "T", "I", "Θ", "N", "J", "B"];
+
+// the number of custom base dimensions (one per new unit type of a units file, e.g. "STORY POINTS")
+pub const CUSTOM_DIMENSIONS: usize = 8;
+
+// a dimension: the exponents of the base dimensions (e.g. L¹·T⁻¹ for a speed), followed by the custom ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Dimension(pub [i8; BASE_DIMENSIONS + CUSTOM_DIMENSIONS]);
+
+impl Dimension {
+    pub const NONE: Dimension = Dimension::of([0, 0, 0, 0, 0, 0, 0, 0]);
+    pub const LENGTH: Dimension = Dimension::of([1, 0, 0, 0, 0, 0, 0, 0]);
+    pub const MASS: Dimension = Dimension::of([0, 1, 0, 0, 0, 0, 0, 0]);
+    pub const TIME: Dimension = Dimension::of([0, 0, 1, 0, 0, 0, 0, 0]);
+    pub const CURRENT: Dimension = Dimension::of([0, 0, 0, 1, 0, 0, 0, 0]);
+    pub const TEMPERATURE: Dimension = Dimension::of([0, 0, 0, 0, 1, 0, 0, 0]);
+    pub const AMOUNT: Dimension = Dimension::of([0, 0, 0, 0, 0, 1, 0, 0]);
+    pub const LUMINOSITY: Dimension = Dimension::of([0, 0, 0, 0, 0, 0, 1, 0]);
+    pub const INFORMATION: Dimension = Dimension::of([0, 0, 0, 0, 0, 0, 0, 1]);
+    pub const AREA: Dimension = Dimension::of([2, 0, 0, 0, 0, 0, 0, 0]);
+    pub const VOLUME: Dimension = Dimension::of([3, 0, 0, 0, 0, 0, 0, 0]);
+    pub const FREQUENCY: Dimension = Dimension::of([0, 0, -1, 0, 0, 0, 0, 0]);
+    pub const ENERGY: Dimension = Dimension::of([2, 1, -2, 0, 0, 0, 0, 0]);
+    pub const FORCE: Dimension = Dimension::of([1, 1, -2, 0, 0, 0, 0, 0]);
+    pub const POWER: Dimension = Dimension::of([2, 1, -3, 0, 0, 0, 0, 0]);
+    pub const PRESSURE: Dimension = Dimension::of([-1, 1, -2, 0, 0, 0, 0, 0]);
+    pub const FUEL_ECONOMY: Dimension = Dimension::of([-2, 0, 0, 0, 0, 0, 0, 0]);
+    pub const SPEED: Dimension = Dimension::of([1, 0, -1, 0, 0, 0, 0, 0]);
+    pub const DATA_RATE: Dimension = Dimension::of([0, 0, -1, 0, 0, 0, 0, 1]);
+    pub const CHARGE: Dimension = Dimension::of([0, 0, 1, 1, 0, 0, 0, 0]);
+    pub const VOLTAGE: Dimension = Dimension::of([2, 1, -3, -1, 0, 0, 0, 0]);
+    pub const RESISTANCE: Dimension = Dimension::of([2, 1, -3, -2, 0, 0, 0, 0]);
+    pub const CONDUCTANCE: Dimension = Dimension::of([-2, -1, 3, 2, 0, 0, 0, 0]);
+    pub const CAPACITANCE: Dimension = Dimension::of([-2, -1, 4, 2, 0, 0, 0, 0]);
+    pub const INDUCTANCE: Dimension = Dimension::of([2, 1, -2, -2, 0, 0, 0, 0]);
+
+    // a dimension made of the (non-custom) base dimensions
+    pub const fn of(exponents: [i8; BASE_DIMENSIONS]) -> Dimension {
+        let mut all = [0; BASE_DIMENSIONS + CUSTOM_DIMENSIONS];
+        let mut i = 0;
+        while i < BASE_DIMENSIONS {
+            all[i] = exponents[i];
+            i += 1;
+        }
+        Dimension(all)
+    }
+
+    // the custom base dimension at the given index, or None if the index is out of range
+    pub fn custom(index: usize) -> Option<Dimension> {
+        let mut exponents = [0; BASE_DIMENSIONS + CUSTOM_DIMENSIONS];
+        *exponents.get_mut(BASE_DIMENSIONS + index)? = 1;
+        Some(Dimension(exponents))
+    }
+
+    // the product of two dimensions (None if an exponent is out of the i8 range)
+    pub fn mul(&self, other: &Dimension) -> Option<Dimension> {
+        let mut exponents = self.0;
+        for (e, o) in exponents.iter_mut().zip(other.0.iter()) {
+            *e = e.checked_add(*o)?;
+        }
+        Some(Dimension(exponents))
+    }
+
+    pub fn div(&self, other: &Dimension) -> Option<Dimension> {
+        self.mul(&other.pow(-1)?)
+    }
+
+    // the power of a dimension (None if an exponent is out of the i8 range)
+    pub fn pow(&self, exponent: i8) -> Option<Dimension> {
+        let mut exponents = self.0;
+        for e in exponents.iter_mut() {
+            *e = e.checked_mul(exponent)?;
+        }
+        Some(Dimension(exponents))
+    }
+
+    pub fn is_dimensionless(&self) -> bool {
+        *self == Dimension::NONE
+    }
+
+    // describe the dimension with the unit types of the custom base dimensions (see UnitRegistry::describe),
+    // e.g. "L·T⁻¹" or "T⁻¹·[STORY POINTS]"
+    pub fn describe(&self, custom_names: &[String]) -> String {
+        if self.is_dimensionless() {
+            return "1".to_string();
+        }
+        let parts: Vec<String> = self
+            .0
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| **e != 0)
+            .map(|(i, e)| {
+                // (a custom base dimension is shown as its unit type, e.g. "[STORY POINTS]")
+                let symbol = match BASE_DIMENSION_SYMBOLS.get(i) {
+                    Some(symbol) => symbol.to_string(),
+                    None => format!(
+                        "[{}]",
+                        custom_names
+                            .get(i - BASE_DIMENSIONS)
+                            .map(|n| n.as_str())
+                            .unwrap_or("?")
+                    ),
+                };
+                if *e == 1 {
+                    symbol
+                } else {
+                    format!("{}{}", symbol, superscript(*e as i32))
+                }
+            })
+            .collect();
+        parts.join("·")
+    }
+}
+
+impl fmt::Display for Dimension {
+    // (without the names of the custom base dimensions)
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.describe(&[]))
+    }
+}
+
+// format an exponent using superscript characters (e.g. -2 → "⁻²")
+pub fn superscript(exponent: i32) -> String {
+    exponent
+        .to_string()
+        .chars()
+        .map(|c| match c {
+            '-' => '⁻',
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            '9' => '⁹',
+            c => c,
+        })
+        .collect()
+}
+
+// a unit resolved from a unit string: either a single unit of a registry
+// or a product/quotient/power of them (e.g. "m/s", "kg⋅m/s²", "kW⋅h/day")
+#[derive(Debug, Clone)]
+pub struct DerivedUnit {
+    pub name: String,
+    pub abbr: String,
+    // the unit type (only set for a single unit of the registry)
+    pub unit_type: Option<String>,
+    pub dimension: Dimension,
+    // the ratios to the coherent SI unit of the dimension, per ratio variant label
+    pub ratios: Vec<(String, f64)>,
+    // the offset of the zero of the unit, in the coherent SI unit (see Unit::offset; 0 for composed units,
+    // whose values are differences, e.g. "psig/s")
+    pub offset: Definition,
+    // the scale of the values of the unit (see Unit::scale; linear for composed units)
+    pub scale: Scale,
+    // the exact ratios, in the order of the ratios (the "exact" feature; only set for the units of an exact
+    // registry, see with_exact_ratios, and None for a decibel unit)
+    #[cfg(feature = "exact")]
+    pub exact_ratios: Option<Vec<Rational>>,
+    // the single unit of the registry this unit is made of (if any)
+    pub unit: Option<Arc<Unit>>,
+}
+
+impl DerivedUnit {
+    // the dimensionless unit "1" (e.g. the numerator of "1/s")
+    pub fn one() -> DerivedUnit {
+        DerivedUnit {
+            name: "1".to_string(),
+            abbr: "1".to_string(),
+            unit_type: None,
+            dimension: Dimension::NONE,
+            ratios: vec![("".to_string(), 1.0)],
+            offset: Definition::ZERO,
+            scale: Scale::Linear,
+            #[cfg(feature = "exact")]
+            exact_ratios: Some(vec![Rational::from_integer(1.into())]),
+            unit: None,
+        }
+    }
+
+    pub fn from_unit(unit_type: &str, unit: &Arc<Unit>) -> DerivedUnit {
+        DerivedUnit {
+            name: unit.name.clone(),
+            abbr: unit.abbr.clone(),
+            unit_type: Some(unit_type.to_string()),
+            dimension: unit.dimension,
+            ratios: unit
+                .ratios
+                .iter()
+                .map(|(label, ratio)| (label.clone(), ratio.value))
+                .collect(),
+            offset: unit.offset,
+            scale: unit.scale,
+            #[cfg(feature = "exact")]
+            exact_ratios: None,
+            unit: Some(unit.clone()),
+        }
+    }
+
+    // a prefixed unit of a registry (e.g. "kPa" or "MiB")
+    pub fn prefixed(unit_type: &str, prefix: &Prefix, unit: &Arc<Unit>) -> DerivedUnit {
+        let base = DerivedUnit::from_unit(unit_type, unit);
+        DerivedUnit {
+            // (e.g. "Kilo" + "pascal")
+            name: format!(
+                "{}{}{}",
+                prefix.name[..1].to_uppercase(),
+                &prefix.name[1..],
+                base.name.to_lowercase()
+            ),
+            abbr: format!("{}{}", prefix.symbols[0], unit.prefixes.symbols()[0]),
+            ratios: base
+                .ratios
+                .iter()
+                .map(|(label, ratio)| (label.clone(), ratio * prefix.factor))
+                .collect(),
+            unit: None,
+            ..base
+        }
+    }
+
+    // the unit with its exact ratios, from the definitions of the ratios of the unit of the registry
+    // and the factor of its prefix (only for an exact registry, see UnitRegistry::set_exact)
+    #[cfg(feature = "exact")]
+    pub fn with_exact_ratios(self, unit: &Unit, prefix: Option<&Prefix>) -> DerivedUnit {
+        let factor = match prefix {
+            Some(prefix) => exact::from_f64(prefix.factor),
+            None => Some(Rational::one()),
+        };
+        DerivedUnit {
+            exact_ratios: match (unit.scale, factor) {
+                (Scale::Decibel, _) | (_, None) => None,
+                (_, Some(factor)) => unit
+                    .ratios
+                    .iter()
+                    .map(|(_, ratio)| {
+                        exact::from_definition(ratio)
+                            .filter(|ratio| !ratio.is_zero())
+                            .map(|ratio| ratio * &factor)
+                    })
+                    .collect(),
+            },
+            ..self
+        }
+    }
+
+    // combine the ratio variants of two units (every variant of one with every variant of the other)
+    fn combine_ratios(&self, other: &DerivedUnit, op: fn(f64, f64) -> f64) -> Vec<(String, f64)> {
+        let mut ratios = Vec::new();
+        for (l_label, l_ratio) in self.ratios.iter() {
+            for (r_label, r_ratio) in other.ratios.iter() {
+                let label = match (l_label.is_empty(), r_label.is_empty()) {
+                    (true, _) => r_label.to_string(),
+                    (false, true) => l_label.to_string(),
+                    (false, false) if l_label == r_label => l_label.to_string(),
+                    (false, false) => format!("{}, {}", l_label, r_label),
+                };
+                ratios.push((label, op(*l_ratio, *r_ratio)));
+            }
+        }
+        ratios
+    }
+
+    // combine the exact ratios of two units (in the order of combine_ratios)
+    #[cfg(feature = "exact")]
+    fn combine_exact_ratios(
+        &self,
+        other: &DerivedUnit,
+        op: fn(&Rational, &Rational) -> Rational,
+    ) -> Option<Vec<Rational>> {
+        let (l_ratios, r_ratios) = (self.exact_ratios.as_ref()?, other.exact_ratios.as_ref()?);
+        Some(
+            l_ratios
+                .iter()
+                .flat_map(|l| r_ratios.iter().map(move |r| op(l, r)))
+                .collect(),
+        )
+    }
+
+    // the product of two units (None if an exponent of the dimension is out of range)
+    pub fn mul(&self, other: &DerivedUnit) -> Option<DerivedUnit> {
+        Some(DerivedUnit {
+            name: format!("{} {}", self.name, other.name),
+            abbr: format!("{}⋅{}", self.abbr, other.abbr),
+            unit_type: None,
+            dimension: self.dimension.mul(&other.dimension)?,
+            ratios: self.combine_ratios(other, |l, r| l * r),
+            offset: Definition::ZERO,
+            scale: Scale::Linear,
+            #[cfg(feature = "exact")]
+            exact_ratios: self.combine_exact_ratios(other, |l, r| l * r),
+            unit: None,
+        })
+    }
+
+    pub fn div(&self, other: &DerivedUnit) -> Option<DerivedUnit> {
+        Some(DerivedUnit {
+            name: format!("{} per {}", self.name, other.name),
+            abbr: format!("{}/{}", self.abbr, other.abbr),
+            unit_type: None,
+            dimension: self.dimension.div(&other.dimension)?,
+            ratios: self.combine_ratios(other, |l, r| l / r),
+            offset: Definition::ZERO,
+            scale: Scale::Linear,
+            #[cfg(feature = "exact")]
+            exact_ratios: self.combine_exact_ratios(other, |l, r| l / r),
+            unit: None,
+        })
+    }
+
+    pub fn pow(&self, exponent: i8) -> Option<DerivedUnit> {
+        Some(DerivedUnit {
+            name: format!("{}{}", self.name, superscript(exponent as i32)),
+            abbr: format!("{}{}", self.abbr, superscript(exponent as i32)),
+            unit_type: None,
+            dimension: self.dimension.pow(exponent)?,
+            ratios: self
+                .ratios
+                .iter()
+                .map(|(label, ratio)| (label.clone(), ratio.powi(exponent as i32)))
+                .collect(),
+            offset: Definition::ZERO,
+            scale: Scale::Linear,
+            #[cfg(feature = "exact")]
+            exact_ratios: self.exact_ratios.as_ref().map(|ratios| {
+                ratios
+                    .iter()
+                    .map(|ratio| ratio.pow(exponent as i32))
+                    .collect()
+            }),
+            unit: None,
+        })
+    }
+
+    // convert a value of the unit into the coherent SI unit of its dimension, with one of the unit ratios:
+    // an affine unit (e.g. °C) scales and shifts the value, an inverse unit (e.g. min/km) inverts it
+    // and a decibel unit (e.g. dBm) raises it to a power of 10
+    pub fn to_base(&self, value: f64, ratio: f64) -> f64 {
+        match self.scale {
+            Scale::Linear => value * ratio + self.offset.value,
+            Scale::Inverse => ratio / value,
+            Scale::Decibel => ratio * 10f64.powf(value / 10.0),
+        }
+    }
+
+    // convert a value in the coherent SI unit of the dimension into the unit (the inverse of to_base)
+    pub fn from_base(&self, value: f64, ratio: f64) -> f64 {
+        match self.scale {
+            Scale::Linear => (value - self.offset.value) / ratio,
+            Scale::Inverse => ratio / value,
+            Scale::Decibel => 10.0 * (value / ratio).log10(),
+        }
+    }
+
+    // whether a value in the coherent SI unit of the dimension has a value in the unit
+    // (e.g. a fuel economy of 0 has no fuel consumption in L/100km, and a power of 0 W has no level in dBm)
+    pub fn has_value(&self, value: f64) -> bool {
+        match self.scale {
+            Scale::Linear => true,
+            Scale::Inverse => value != 0.0,
+            Scale::Decibel => value > 0.0,
+        }
+    }
+
+    // the exact ratio of the ratio variant with the given label
+    #[cfg(feature = "exact")]
+    fn exact_ratio(&self, label: &str) -> Option<&Rational> {
+        let index = self.ratios.iter().position(|(l, _)| l == label)?;
+        self.exact_ratios.as_ref()?.get(index)
+    }
+
+    // the exact counterpart of to_base, with the exact ratio of the given ratio variant
+    // (None for a decibel unit, or for 0 of an inverse unit)
+    #[cfg(feature = "exact")]
+    pub fn to_base_exact(&self, value: &Rational, label: &str) -> Option<Rational> {
+        let ratio = self.exact_ratio(label)?;
+        match self.scale {
+            Scale::Linear => Some(value * ratio + exact::from_definition(&self.offset)?),
+            Scale::Inverse if !value.is_zero() => Some(ratio / value),
+            _ => None,
+        }
+    }
+
+    // the exact counterpart of from_base (see to_base_exact)
+    #[cfg(feature = "exact")]
+    pub fn from_base_exact(&self, value: &Rational, label: &str) -> Option<Rational> {
+        let ratio = self.exact_ratio(label)?;
+        match self.scale {
+            Scale::Linear => Some((value - exact::from_definition(&self.offset)?) / ratio),
+            Scale::Inverse if !value.is_zero() => Some(ratio / value),
+            _ => None,
+        }
+    }
+
+    // the unit type of the unit (or of its dimension), or the dimension itself if there is no such unit type
+    pub fn describe(&self, registry: &UnitRegistry) -> String {
+        registry.describe(self.unit_type.as_deref(), &self.dimension)
+    }
+}
@@ -0,0 +1,173 @@
+use std::fmt;
+
+use crate::units::{DEFAULT_PRECISION, MAX_PRECISION};
+
+// errors that can occur while parsing and converting units
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConvertError {
+    // the input has no "=" or " to " separator between the source and the target
+    MissingSeparator,
+    // the input has no source values
+    MissingSourceValues,
+    // a value-unit pair couldn't be parsed
+    InvalidValue(String),
+    // a value isn't a finite number (e.g. "1e400" overflows a f64, and "1/0" has a divisor of 0)
+    NonFiniteValue(String),
+    // the unit is not known
+    UnknownUnit(String),
+    // the unit type is not known
+    UnknownUnitType(String),
+    // the unit matches units of several unit types and the conversion can't tell which one is meant
+    AmbiguousUnit {
+        unit: String,
+        candidates: Vec<String>,
+    },
+    // the source fits none of the units an ambiguous target unit can be
+    AmbiguousUnitMismatch {
+        left: String,
+        unit: String,
+        candidates: Vec<String>,
+    },
+    // one or more source units don't belong to the type (category) of the target unit
+    UnitTypeMismatch {
+        units: Vec<String>,
+        unit_type: String,
+    },
+    // the operands of an operation (or the source and the target) have incompatible unit types
+    DimensionMismatch {
+        left: String,
+        right: String,
+    },
+    // the arithmetic expression is not valid
+    InvalidExpression(String),
+    // an exponent of a unit (or of the dimension of a product/quotient) is out of range
+    ExponentOutOfRange(String),
+    // the values of a unit with an offset zero point can't be summed up or used in arithmetic operations
+    OffsetUnitArithmetic(String),
+    // absolute values (e.g. temperatures) can't be added together or subtracted from a difference
+    AbsoluteValueArithmetic {
+        unit_type: String,
+        difference_type: String,
+    },
+    // the values of a non-linear unit (e.g. a pace or a level in dBm) can't be summed up
+    // or used in arithmetic operations
+    NonLinearUnitArithmetic(String),
+    // the source has no value in the non-linear target unit (e.g. 0 mpg in L/100km or 0 W in dBm)
+    ValueOutOfRange {
+        value: String,
+        unit: String,
+    },
+    // the units file can't be read or is not valid
+    InvalidUnitsFile(String),
+    // a unit of the units file uses an abbreviation or alias of a known unit (the unit is skipped)
+    UnitConflict {
+        unit: String,
+        symbol: String,
+        existing: String,
+    },
+    // the precision is not an integer (or "*")
+    InvalidPrecision(String),
+    // the precision is negative
+    NegativePrecision(String),
+    // the precision is higher than MAX_PRECISION
+    PrecisionTooHigh(String),
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConvertError::MissingSeparator => {
+                write!(
+                    f,
+                    "[ Missing \"=\" or \"to\" between the source and the target unit ]"
+                )
+            }
+            ConvertError::MissingSourceValues => write!(f, "[ Missing source values ]"),
+            ConvertError::InvalidValue(v) => write!(f, "[ Invalid value: {} ]", v),
+            ConvertError::NonFiniteValue(v) => {
+                write!(f, "[ Value out of range: {} is not a finite number ]", v)
+            }
+            ConvertError::UnknownUnit(u) => write!(f, "[ Unknown unit: {} ]", u),
+            ConvertError::UnknownUnitType(t) => write!(f, "[ Unknown unit type: {} ]", t),
+            ConvertError::AmbiguousUnit { unit, candidates } => write!(
+                f,
+                "[ Ambiguous unit '{}': it can be {} ]",
+                unit,
+                candidates.join(" or ")
+            ),
+            ConvertError::AmbiguousUnitMismatch {
+                left,
+                unit,
+                candidates,
+            } => write!(
+                f,
+                "[ Incompatible unit types: '{}' and '{}', which can be {} ]",
+                left,
+                unit,
+                candidates.join(" or ")
+            ),
+            ConvertError::UnitTypeMismatch { units, unit_type } => {
+                let lines: Vec<String> = units
+                    .iter()
+                    .map(|u| format!("[ Unit '{}' not found in type '{}' ]", u, unit_type))
+                    .collect();
+                write!(f, "{}", lines.join("\n"))
+            }
+            ConvertError::DimensionMismatch { left, right } => {
+                write!(f, "[ Incompatible unit types: '{}' and '{}' ]", left, right)
+            }
+            ConvertError::InvalidExpression(e) => write!(f, "[ Invalid expression: {} ]", e),
+            ConvertError::ExponentOutOfRange(e) => write!(f, "[ Exponent out of range: {} ]", e),
+            ConvertError::OffsetUnitArithmetic(u) => write!(
+                f,
+                "[ The unit '{}' has an offset zero point and can't be combined with other values ]",
+                u
+            ),
+            ConvertError::AbsoluteValueArithmetic {
+                unit_type,
+                difference_type,
+            } => write!(
+                f,
+                "[ {} values can't be added together or subtracted from a {} ]",
+                unit_type, difference_type
+            ),
+            ConvertError::NonLinearUnitArithmetic(u) => write!(
+                f,
+                "[ The unit '{}' isn't linear and can't be combined with other values ]",
+                u
+            ),
+            ConvertError::ValueOutOfRange { value, unit } => write!(
+                f,
+                "[ Value out of range: {} has no value in {} ]",
+                value, unit
+            ),
+            ConvertError::InvalidUnitsFile(e) => write!(f, "[ Invalid units file: {} ]", e),
+            ConvertError::UnitConflict {
+                unit,
+                symbol,
+                existing,
+            } => write!(
+                f,
+                "[ Unit conflict: '{}' of {} is already used by {}, the unit is skipped ]",
+                symbol, unit, existing
+            ),
+            ConvertError::InvalidPrecision(p) => write!(
+                f,
+                "Not a valid precision: {} (using the default precision of {} instead)",
+                p, DEFAULT_PRECISION
+            ),
+            ConvertError::NegativePrecision(p) => write!(
+                f,
+                "Can't use negative precision: {} (using the default precision of {} instead)",
+                p, DEFAULT_PRECISION
+            ),
+            ConvertError::PrecisionTooHigh(p) => write!(
+                f,
+                "Precision too high: {} (using the max allowed precision of {} instead)",
+                p, MAX_PRECISION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConvertError {}
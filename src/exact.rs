@@ -0,0 +1,86 @@
+// exact rational arithmetic for the conversions (the "exact" feature):
+// the values and the unit ratios are rational numbers of big integers, which only become decimal numbers
+// when they're formatted (see UnitRegistry::set_exact)
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive, Zero};
+
+use crate::constants::Definition;
+
+pub type Rational = BigRational;
+
+// the largest exponent of a decimal number (the values beyond it aren't finite or are 0 as a f64,
+// and their powers of 10 would only waste time)
+const MAX_EXPONENT: i32 = 400;
+
+// parse a decimal number exactly, e.g. "5", "-3.14", "+.5" or "6.02e23"
+// (None if it isn't finite as a f64 or its exponent is out of range, e.g. "1e400")
+pub fn parse_decimal(s: &str) -> Option<Rational> {
+    if !s.parse::<f64>().is_ok_and(f64::is_finite) {
+        return None;
+    }
+    let (mantissa, exponent) = match s.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().ok()?),
+        None => (s, 0),
+    };
+    if exponent.abs() > MAX_EXPONENT {
+        return None;
+    }
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let digits = format!("{}{}", integer, fraction);
+    if !digits
+        .trim_start_matches(['+', '-'])
+        .starts_with(|c: char| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let numerator: BigInt = digits.parse().ok()?;
+    Some(Rational::from_integer(numerator) * pow10(exponent - fraction.len() as i32))
+}
+
+// 10 to the power of the exponent
+fn pow10(exponent: i32) -> Rational {
+    let power = Rational::from_integer(BigInt::from(10).pow(exponent.unsigned_abs()));
+    if exponent < 0 {
+        power.recip()
+    } else {
+        power
+    }
+}
+
+// the exact value of a definition (see constants.rs), or None if it has none (e.g. for an infinite value)
+pub fn from_definition(definition: &Definition) -> Option<Rational> {
+    let (numerator, denominator, exponent) = definition.parts()?;
+    Some(Rational::new(numerator.into(), denominator.into()) * pow10(exponent))
+}
+
+// the exact value of a floating point value: the shortest decimal number that rounds to it
+// (see Definition::from); None for infinite values and NaN
+pub fn from_f64(value: f64) -> Option<Rational> {
+    from_definition(&Definition::from(value))
+}
+
+// the closest floating point number
+pub fn to_f64(value: &Rational) -> f64 {
+    value.to_f64().unwrap_or(f64::NAN)
+}
+
+// format a number with the specified precision: rounded half away from zero, without trailing zeros
+// (the exact counterpart of utils::format_number)
+pub fn format(value: &Rational, precision: i32) -> String {
+    let scale = BigInt::from(10).pow(precision as u32);
+    let scaled = (value * Rational::from_integer(scale.clone()))
+        .round()
+        .to_integer();
+    let (integer, fraction) = (scaled.abs() / &scale, scaled.abs() % &scale);
+    let sign = if scaled.is_negative() { "-" } else { "" };
+    if fraction.is_zero() {
+        return format!("{}{}", sign, integer);
+    }
+    let fraction = format!("{:0>1$}", fraction.to_string(), precision as usize);
+    format!("{}{}.{}", sign, integer, fraction.trim_end_matches('0'))
+}
@@ -0,0 +1,725 @@
+use indexmap::IndexMap;
+
+use crate::dimension::{DerivedUnit, Dimension};
+use crate::error::ConvertError;
+#[cfg(feature = "exact")]
+use crate::exact::Rational;
+use crate::registry::UnitRegistry;
+use crate::units::Scale;
+#[cfg(feature = "exact")]
+use crate::utils::parse_exact_value;
+use crate::utils::{format_value, parse_value_unit, split_value_unit_pairs};
+#[cfg(feature = "exact")]
+use num_traits::Zero;
+
+// a quantity: a value in the coherent SI unit of its dimension (per ratio variant),
+// e.g. a length in meters or a speed in meters per second
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quantity {
+    // the unit type of the quantity (only known if it's made of units of the same unit type)
+    pub unit_type: Option<String>,
+    pub dimension: Dimension,
+    // the value in the coherent SI unit, per ratio variant label (e.g. "Imperial" / "US liquid")
+    pub values: IndexMap<String, f64>,
+    // the exact values, per ratio variant label (the "exact" feature),
+    // or None if they can't be exact (e.g. for a level in dBm or after a division by 0)
+    #[cfg(feature = "exact")]
+    pub exact_values: Option<IndexMap<String, Rational>>,
+}
+
+impl Quantity {
+    // a plain number (without a unit, and without an exact value: see of_unit_exact)
+    pub fn number(value: f64) -> Quantity {
+        let mut values = IndexMap::new();
+        values.insert("".to_string(), value);
+        Quantity {
+            unit_type: None,
+            dimension: Dimension::NONE,
+            values,
+            #[cfg(feature = "exact")]
+            exact_values: None,
+        }
+    }
+
+    // a value of the given unit (one value per ratio variant of the unit, without exact values)
+    pub fn of_unit(value: f64, unit: &DerivedUnit) -> Quantity {
+        Quantity {
+            unit_type: unit.unit_type.clone(),
+            dimension: unit.dimension,
+            values: unit
+                .ratios
+                .iter()
+                .map(|(label, ratio)| (label.clone(), unit.to_base(value, *ratio)))
+                .collect(),
+            #[cfg(feature = "exact")]
+            exact_values: None,
+        }
+    }
+
+    // a value of the given unit with its exact value (e.g. the value of "1/3", which a f64 can't hold)
+    #[cfg(feature = "exact")]
+    pub fn of_unit_exact(
+        value: f64,
+        exact_value: Option<&Rational>,
+        unit: &DerivedUnit,
+    ) -> Quantity {
+        Quantity {
+            exact_values: exact_value.and_then(|value| Quantity::exact_values_of_unit(value, unit)),
+            ..Quantity::of_unit(value, unit)
+        }
+    }
+
+    #[cfg(feature = "exact")]
+    fn exact_values_of_unit(
+        value: &Rational,
+        unit: &DerivedUnit,
+    ) -> Option<IndexMap<String, Rational>> {
+        unit.ratios
+            .iter()
+            .map(|(label, _)| Some((label.clone(), unit.to_base_exact(value, label)?)))
+            .collect()
+    }
+
+    // the description of the quantity's unit type or dimension (used in error messages)
+    pub fn describe(&self, registry: &UnitRegistry) -> String {
+        registry.describe(self.unit_type.as_deref(), &self.dimension)
+    }
+
+    fn dimension_mismatch(&self, other: &Quantity, registry: &UnitRegistry) -> ConvertError {
+        ConvertError::DimensionMismatch {
+            left: self.describe(registry),
+            right: other.describe(registry),
+        }
+    }
+
+    // combine the per ratio variant values of two quantities (see combine_values)
+    fn combine(&self, other: &Quantity, op: fn(f64, f64) -> f64) -> IndexMap<String, f64> {
+        combine_values(&self.values, &other.values, &0.0, |l, r| Some(op(*l, *r))).unwrap()
+    }
+
+    // combine the exact values of two quantities (None if either of them isn't exact or an operation fails)
+    #[cfg(feature = "exact")]
+    fn combine_exact(
+        &self,
+        other: &Quantity,
+        op: fn(&Rational, &Rational) -> Option<Rational>,
+    ) -> Option<IndexMap<String, Rational>> {
+        combine_values(
+            self.exact_values.as_ref()?,
+            other.exact_values.as_ref()?,
+            &Rational::zero(),
+            op,
+        )
+    }
+
+    // the unit type of the sum (or the difference) of two quantities (which need to have the same dimension):
+    // for a unit type with a difference type (e.g. TEMPERATURE), an absolute value plus or minus a difference
+    // is an absolute value and the difference of two absolute values is a difference
+    fn sum_unit_type(
+        &self,
+        other: &Quantity,
+        subtract: bool,
+        registry: &UnitRegistry,
+    ) -> Result<Option<String>, ConvertError> {
+        if self.dimension != other.dimension {
+            return Err(self.dimension_mismatch(other, registry));
+        }
+        let (l, r) = match (&self.unit_type, &other.unit_type) {
+            (Some(l), Some(r)) => (l, r),
+            (l, r) => return Ok(l.clone().or(r.clone())),
+        };
+        let absolute_value_arithmetic =
+            |unit_type: &str, difference_type: &str| ConvertError::AbsoluteValueArithmetic {
+                unit_type: unit_type.to_string(),
+                difference_type: difference_type.to_string(),
+            };
+        match (registry.difference_type(l), registry.difference_type(r)) {
+            // two absolute values
+            (Some(difference_type), _) if l == r => {
+                if subtract {
+                    Ok(Some(difference_type.to_string()))
+                } else {
+                    Err(absolute_value_arithmetic(l, difference_type))
+                }
+            }
+            // an absolute value and a difference
+            (Some(difference_type), _) if difference_type == r => Ok(Some(l.clone())),
+            (_, Some(difference_type)) if difference_type == l => {
+                if subtract {
+                    Err(absolute_value_arithmetic(r, difference_type))
+                } else {
+                    Ok(Some(r.clone()))
+                }
+            }
+            _ if !registry.unit_types_match(l, r) => Err(self.dimension_mismatch(other, registry)),
+            _ => Ok(Some(l.clone())),
+        }
+    }
+
+    // the unit type of the product/quotient of two quantities
+    // (only kept if one of the quantities is a plain number)
+    fn product_unit_type(&self, other: &Quantity) -> Option<String> {
+        if other.unit_type.is_none() && other.dimension.is_dimensionless() {
+            self.unit_type.clone()
+        } else if self.unit_type.is_none() && self.dimension.is_dimensionless() {
+            other.unit_type.clone()
+        } else {
+            None
+        }
+    }
+
+    pub fn add(&self, other: &Quantity, registry: &UnitRegistry) -> Result<Quantity, ConvertError> {
+        Ok(Quantity {
+            unit_type: self.sum_unit_type(other, false, registry)?,
+            dimension: self.dimension,
+            values: self.combine(other, |l, r| l + r),
+            #[cfg(feature = "exact")]
+            exact_values: self.combine_exact(other, |l, r| Some(l + r)),
+        })
+    }
+
+    pub fn sub(&self, other: &Quantity, registry: &UnitRegistry) -> Result<Quantity, ConvertError> {
+        Ok(Quantity {
+            unit_type: self.sum_unit_type(other, true, registry)?,
+            dimension: self.dimension,
+            values: self.combine(other, |l, r| l - r),
+            #[cfg(feature = "exact")]
+            exact_values: self.combine_exact(other, |l, r| Some(l - r)),
+        })
+    }
+
+    // the product of two quantities (None if an exponent of the dimension is out of range)
+    pub fn mul(&self, other: &Quantity, registry: &UnitRegistry) -> Option<Quantity> {
+        let dimension = self.dimension.mul(&other.dimension)?;
+        Some(Quantity {
+            unit_type: self.product_unit_type(other).or_else(|| {
+                registry.product_unit_type(
+                    self.unit_type.as_deref(),
+                    other.unit_type.as_deref(),
+                    &dimension,
+                )
+            }),
+            dimension,
+            values: self.combine(other, |l, r| l * r),
+            #[cfg(feature = "exact")]
+            exact_values: self.combine_exact(other, |l, r| Some(l * r)),
+        })
+    }
+
+    pub fn div(&self, other: &Quantity) -> Option<Quantity> {
+        let unit_type = if other.unit_type.is_none() && other.dimension.is_dimensionless() {
+            self.unit_type.clone()
+        } else {
+            None
+        };
+        Some(Quantity {
+            unit_type,
+            dimension: self.dimension.div(&other.dimension)?,
+            values: self.combine(other, |l, r| l / r),
+            #[cfg(feature = "exact")]
+            exact_values: self.combine_exact(other, |l, r| (!r.is_zero()).then(|| l / r)),
+        })
+    }
+
+    // the reciprocal of the quantity (e.g. a speed for a pace in s/m), which has no unit type
+    pub fn recip(&self) -> Option<Quantity> {
+        Some(Quantity {
+            unit_type: None,
+            dimension: self.dimension.pow(-1)?,
+            values: self
+                .values
+                .iter()
+                .map(|(l, v)| (l.clone(), 1.0 / v))
+                .collect(),
+            #[cfg(feature = "exact")]
+            exact_values: self.exact_values.as_ref().and_then(|values| {
+                values
+                    .iter()
+                    .map(|(l, v)| Some((l.clone(), (!v.is_zero()).then(|| v.recip())?)))
+                    .collect()
+            }),
+        })
+    }
+
+    pub fn neg(&self) -> Quantity {
+        Quantity {
+            unit_type: self.unit_type.clone(),
+            dimension: self.dimension,
+            values: self.values.iter().map(|(l, v)| (l.clone(), -v)).collect(),
+            #[cfg(feature = "exact")]
+            exact_values: self
+                .exact_values
+                .as_ref()
+                .map(|values| values.iter().map(|(l, v)| (l.clone(), -v)).collect()),
+        }
+    }
+}
+
+// combine the per ratio variant values of two quantities:
+// a single (unlabeled) value is applied to every variant of the other quantity,
+// otherwise the values are combined by variant label (None if an operation fails)
+fn combine_values<T>(
+    l_values: &IndexMap<String, T>,
+    r_values: &IndexMap<String, T>,
+    zero: &T,
+    op: impl Fn(&T, &T) -> Option<T>,
+) -> Option<IndexMap<String, T>> {
+    let mut values = IndexMap::new();
+    if l_values.len() == 1 || r_values.len() == 1 {
+        for (l_label, l_value) in l_values.iter() {
+            for (r_label, r_value) in r_values.iter() {
+                let label = if l_label.is_empty() { r_label } else { l_label };
+                values.insert(label.clone(), op(l_value, r_value)?);
+            }
+        }
+    } else {
+        for (label, l_value) in l_values.iter() {
+            let r_value = r_values.get(label).unwrap_or(zero);
+            values.insert(label.clone(), op(l_value, r_value)?);
+        }
+        for (label, r_value) in r_values.iter() {
+            if !values.contains_key(label) {
+                values.insert(label.clone(), op(zero, r_value)?);
+            }
+        }
+    }
+    Some(values)
+}
+
+// named quantities that can be used in expressions (e.g. "x" in "x * 2")
+pub type Variables = IndexMap<String, Quantity>;
+
+// a list of space separated value-unit pairs (e.g. "5ft 10in") of the same dimension, which are summed up
+pub struct MixedUnits {
+    pub pairs: Vec<(f64, DerivedUnit)>,
+    // the exact values of the pairs (the "exact" feature, see parse_exact_value)
+    #[cfg(feature = "exact")]
+    exact_values: Vec<Option<Rational>>,
+}
+
+impl MixedUnits {
+    // parse the value-unit pairs, looking up the units preferably in the given unit type
+    // (all the units need to have the dimension of the first unit)
+    pub fn parse(
+        registry: &UnitRegistry,
+        value_unit_pairs_str: &str,
+        unit_type: Option<&str>,
+    ) -> Result<MixedUnits, ConvertError> {
+        let mut pairs: Vec<(f64, DerivedUnit)> = Vec::new();
+        #[cfg(feature = "exact")]
+        let mut exact_values = Vec::new();
+        let mut mismatched: Vec<String> = Vec::new();
+        for pair in split_value_unit_pairs(value_unit_pairs_str) {
+            let (value, unit_str) = parse_value_unit(pair)?;
+            match registry.resolve_unit(&unit_str, unit_type) {
+                Ok(unit)
+                    if pairs
+                        .first()
+                        .map(|(_, first)| first.dimension == unit.dimension)
+                        .unwrap_or(true) =>
+                {
+                    pairs.push((value, unit));
+                    #[cfg(feature = "exact")]
+                    exact_values.push(
+                        registry
+                            .is_exact()
+                            .then(|| parse_exact_value(pair))
+                            .flatten(),
+                    );
+                }
+                Err(
+                    e @ (ConvertError::ExponentOutOfRange(_) | ConvertError::AmbiguousUnit { .. }),
+                ) => return Err(e),
+                _ => mismatched.push(unit_str),
+            }
+        }
+        if pairs.len() > 1 {
+            check_single_values(registry, pairs.iter().map(|(_, unit)| unit), true)?;
+        }
+        if !mismatched.is_empty() {
+            let unit_type = pairs
+                .first()
+                .map(|(_, unit)| unit.describe(registry))
+                .or(unit_type.map(|t| t.to_string()))
+                .unwrap_or_default();
+            return Err(ConvertError::UnitTypeMismatch {
+                units: mismatched,
+                unit_type,
+            });
+        }
+        Ok(MixedUnits {
+            pairs,
+            #[cfg(feature = "exact")]
+            exact_values,
+        })
+    }
+
+    // the values combined by unit and sorted (larger units first)
+    pub fn combined(&self) -> Vec<(f64, String)> {
+        // combine values by unit and keep track of the first ratio value
+        let mut unit_values = Vec::<(String, f64, f64)>::new();
+        for (value, unit) in self.pairs.iter() {
+            // get the first ratio value for sorting
+            let first_ratio_value = unit.ratios.first().map(|(_, ratio)| *ratio).unwrap_or(0.0);
+            // find existing entry or add new one
+            if let Some(index) = unit_values
+                .iter()
+                .position(|(abbr, _, _)| *abbr == unit.abbr)
+            {
+                unit_values[index].1 += value;
+            } else {
+                unit_values.push((unit.abbr.clone(), *value, first_ratio_value));
+            }
+        }
+        // sort by the first ratio value (descending, i.e. so that the larger units go first)
+        unit_values.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal));
+        unit_values
+            .into_iter()
+            .map(|(abbr, value, _)| (value, abbr))
+            .collect()
+    }
+
+    // the value of a pair
+    fn pair_quantity(&self, i: usize) -> Quantity {
+        let (value, unit) = &self.pairs[i];
+        #[cfg(feature = "exact")]
+        return Quantity::of_unit_exact(*value, self.exact_values[i].as_ref(), unit);
+        #[cfg(not(feature = "exact"))]
+        Quantity::of_unit(*value, unit)
+    }
+
+    // the sum of all values
+    pub fn quantity(&self, registry: &UnitRegistry) -> Result<Quantity, ConvertError> {
+        let mut total: Option<Quantity> = None;
+        for i in 0..self.pairs.len() {
+            let q = self.pair_quantity(i);
+            total = Some(match total {
+                Some(t) => t.add(&q, registry)?,
+                None => q,
+            });
+        }
+        total.ok_or(ConvertError::MissingSourceValues)
+    }
+}
+
+// the values of units with an offset (e.g. a gauge pressure) or of non-linear units (e.g. a pace or a level in dBm)
+// can't be summed up or used in arithmetic operations,
+// except for sums of the units of a unit type with a difference type (e.g. "20 °C + 5 Δ°C", see Quantity::add)
+fn check_single_values<'a>(
+    registry: &UnitRegistry,
+    mut units: impl Iterator<Item = &'a DerivedUnit>,
+    sums_only: bool,
+) -> Result<(), ConvertError> {
+    let has_difference_type = |unit: &DerivedUnit| {
+        unit.unit_type
+            .as_deref()
+            .and_then(|t| registry.difference_type(t))
+            .is_some()
+    };
+    match units.find(|unit| {
+        unit.scale != Scale::Linear
+            || (unit.offset.value != 0.0 && !(sums_only && has_difference_type(unit)))
+    }) {
+        Some(unit) if unit.scale != Scale::Linear => {
+            Err(ConvertError::NonLinearUnitArithmetic(unit.abbr.clone()))
+        }
+        Some(unit) => Err(ConvertError::OffsetUnitArithmetic(unit.abbr.clone())),
+        None => Ok(()),
+    }
+}
+
+// the display string of value-unit pairs (e.g. "5 ft 10 in")
+pub fn display_pairs(pairs: &[(f64, String)]) -> String {
+    pairs
+        .iter()
+        .map(|(value, abbr)| format!("{} {}", format_value(value), abbr))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    LParen,
+    RParen,
+    Op(char),
+    // a number or a list of value-unit pairs
+    Term(String),
+}
+
+fn is_op(c: char) -> bool {
+    matches!(c, '+' | '-' | '*' | '/' | '×' | '÷')
+}
+
+fn normalize_op(c: char) -> char {
+    match c {
+        '×' => '*',
+        '÷' => '/',
+        c => c,
+    }
+}
+
+// split an expression into tokens:
+// operators have to be separated by spaces (to not be confused with signs, fractions and units),
+// except for "*" and "/" (and "×" and "÷") at the start/end of a word, e.g. "(3 ft)*2"
+fn tokenize(input: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut term: Vec<&str> = Vec::new();
+    let spaced = input.replace('(', " ( ").replace(')', " ) ");
+
+    fn flush(tokens: &mut Vec<Token>, term: &mut Vec<&str>) {
+        if term.is_empty() {
+            return;
+        }
+        let t = term.join(" ");
+        term.clear();
+        // a signed term right after a closing parenthesis is a binary operation (e.g. "(3 ft)-4 in")
+        if tokens.last() == Some(&Token::RParen) && (t.starts_with('+') || t.starts_with('-')) {
+            let (op, rest) = t.split_at(1);
+            tokens.push(Token::Op(op.chars().next().unwrap()));
+            if !rest.trim().is_empty() {
+                tokens.push(Token::Term(rest.trim().to_string()));
+            }
+        } else {
+            tokens.push(Token::Term(t));
+        }
+    }
+
+    for word in spaced.split_whitespace() {
+        if word == "(" || word == ")" {
+            flush(&mut tokens, &mut term);
+            tokens.push(if word == "(" {
+                Token::LParen
+            } else {
+                Token::RParen
+            });
+            continue;
+        }
+        let mut chars = word.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            if is_op(c) {
+                flush(&mut tokens, &mut term);
+                tokens.push(Token::Op(normalize_op(c)));
+                continue;
+            }
+        }
+        let mut w = word;
+        while let Some(c) = w
+            .chars()
+            .next()
+            .filter(|c| is_op(*c) && !matches!(c, '+' | '-'))
+        {
+            flush(&mut tokens, &mut term);
+            tokens.push(Token::Op(normalize_op(c)));
+            w = &w[c.len_utf8()..];
+        }
+        let mut trailing_ops = Vec::new();
+        while let Some(c) = w
+            .chars()
+            .last()
+            .filter(|c| is_op(*c) && !matches!(c, '+' | '-'))
+        {
+            trailing_ops.insert(0, Token::Op(normalize_op(c)));
+            w = &w[..w.len() - c.len_utf8()];
+        }
+        if !w.is_empty() {
+            term.push(w);
+        }
+        if !trailing_ops.is_empty() {
+            flush(&mut tokens, &mut term);
+            tokens.extend(trailing_ops);
+        }
+    }
+    flush(&mut tokens, &mut term);
+    tokens
+}
+
+// the result of an evaluated expression
+pub struct Expression {
+    pub quantity: Quantity,
+    // the normalized display string of the expression (e.g. "(3 ft 4 in + 1 yd) * 2")
+    pub display: String,
+    // all the value-unit pairs of the expression
+    pub sources: Vec<(f64, String)>,
+}
+
+struct Parser<'a> {
+    registry: &'a UnitRegistry,
+    tokens: Vec<Token>,
+    pos: usize,
+    unit_type: Option<&'a str>,
+    variables: &'a Variables,
+    sources: Vec<(f64, String)>,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    // expression := term (("+" | "-") term)*
+    fn expression(&mut self) -> Result<(Quantity, String), ConvertError> {
+        let (mut q, mut d) = self.term()?;
+        while let Some(Token::Op(op @ ('+' | '-'))) = self.peek().cloned() {
+            self.next();
+            let (r, rd) = self.term()?;
+            q = if op == '+' {
+                q.add(&r, self.registry)?
+            } else {
+                q.sub(&r, self.registry)?
+            };
+            d = format!("{} {} {}", d, op, rd);
+        }
+        Ok((q, d))
+    }
+
+    // term := unary (("*" | "/") unary)*
+    fn term(&mut self) -> Result<(Quantity, String), ConvertError> {
+        let (mut q, mut d) = self.unary()?;
+        while let Some(Token::Op(op @ ('*' | '/'))) = self.peek().cloned() {
+            self.next();
+            let (r, rd) = self.unary()?;
+            d = format!("{} {} {}", d, op, rd);
+            q = if op == '*' {
+                q.mul(&r, self.registry)
+            } else {
+                q.div(&r)
+            }
+            .ok_or_else(|| ConvertError::ExponentOutOfRange(d.clone()))?;
+        }
+        Ok((q, d))
+    }
+
+    // unary := ("+" | "-") unary | primary
+    fn unary(&mut self) -> Result<(Quantity, String), ConvertError> {
+        match self.peek() {
+            Some(Token::Op('-')) => {
+                self.next();
+                let (q, d) = self.unary()?;
+                Ok((q.neg(), format!("-{}", d)))
+            }
+            Some(Token::Op('+')) => {
+                self.next();
+                self.unary()
+            }
+            _ => self.primary(),
+        }
+    }
+
+    // primary := "(" expression ")" | number | variable | value-unit pairs
+    fn primary(&mut self) -> Result<(Quantity, String), ConvertError> {
+        match self.next() {
+            Some(Token::LParen) => {
+                let (q, d) = self.expression()?;
+                match self.next() {
+                    Some(Token::RParen) => Ok((q, format!("({})", d))),
+                    _ => Err(ConvertError::InvalidExpression(
+                        "missing closing parenthesis".to_string(),
+                    )),
+                }
+            }
+            Some(Token::Term(t)) => self.value(&t),
+            Some(Token::RParen) => Err(ConvertError::InvalidExpression(
+                "unexpected closing parenthesis".to_string(),
+            )),
+            Some(Token::Op(op)) => Err(ConvertError::InvalidExpression(format!(
+                "unexpected operator '{}'",
+                op
+            ))),
+            None => Err(ConvertError::InvalidExpression(
+                "unexpected end of expression".to_string(),
+            )),
+        }
+    }
+
+    fn value(&mut self, t: &str) -> Result<(Quantity, String), ConvertError> {
+        if let Some(quantity) = self.variables.get(t) {
+            return Ok((quantity.clone(), t.to_string()));
+        }
+        if split_value_unit_pairs(t).is_empty() {
+            // a plain number
+            return match parse_value_unit(t) {
+                Ok((value, unit)) if unit.is_empty() => {
+                    #[cfg(not(feature = "exact"))]
+                    let quantity = Quantity::number(value);
+                    #[cfg(feature = "exact")]
+                    let quantity = Quantity::of_unit_exact(
+                        value,
+                        self.registry
+                            .is_exact()
+                            .then(|| parse_exact_value(t))
+                            .flatten()
+                            .as_ref(),
+                        &DerivedUnit::one(),
+                    );
+                    Ok((quantity, format_value(&value)))
+                }
+                _ => Err(ConvertError::InvalidValue(t.to_string())),
+            };
+        }
+        let mixed_units = MixedUnits::parse(self.registry, t, self.unit_type)?;
+        if self.tokens.len() > 1 {
+            let sums_only = !self
+                .tokens
+                .iter()
+                .any(|token| matches!(token, Token::Op('*' | '/')));
+            check_single_values(
+                self.registry,
+                mixed_units.pairs.iter().map(|(_, unit)| unit),
+                sums_only,
+            )?;
+        }
+        let combined = mixed_units.combined();
+        let display = display_pairs(&combined);
+        self.sources.extend(combined);
+        Ok((mixed_units.quantity(self.registry)?, display))
+    }
+}
+
+impl UnitRegistry {
+    // evaluate an arithmetic expression of quantities and variables
+    // (with the units looked up preferably in the given unit type),
+    // e.g. "(3 ft + 4 in) * 2", "2 GB / 8", "100 km / 2 hr" or "x * 2 + 1 ft"
+    pub fn evaluate(
+        &self,
+        input: &str,
+        unit_type: Option<&str>,
+        variables: &Variables,
+    ) -> Result<Expression, ConvertError> {
+        let tokens = tokenize(input);
+        if tokens.is_empty() {
+            return Err(ConvertError::MissingSourceValues);
+        }
+        if let [Token::Term(t)] = tokens.as_slice() {
+            // a single list of value-unit pairs (without operators) needs at least one pair
+            if !variables.contains_key(t) && split_value_unit_pairs(t).is_empty() {
+                return Err(ConvertError::MissingSourceValues);
+            }
+        }
+        let mut parser = Parser {
+            registry: self,
+            tokens,
+            pos: 0,
+            unit_type,
+            variables,
+            sources: Vec::new(),
+        };
+        let (quantity, display) = parser.expression()?;
+        if let Some(token) = parser.peek() {
+            return Err(ConvertError::InvalidExpression(match token {
+                Token::RParen => "unexpected closing parenthesis".to_string(),
+                _ => "missing operator".to_string(),
+            }));
+        }
+        Ok(Expression {
+            quantity,
+            display,
+            sources: parser.sources,
+        })
+    }
+}
@@ -1,5 +1,368 @@
 #[macro_use]
 extern crate lazy_static;
 
+pub mod config;
+pub mod constants;
+pub mod dimension;
+pub mod error;
+#[cfg(feature = "exact")]
+pub mod exact;
+pub mod expr;
+pub mod output;
+pub mod registry;
 pub mod units;
 pub mod utils;
+
+use dimension::DerivedUnit;
+pub use error::ConvertError;
+#[cfg(feature = "exact")]
+use exact::Rational;
+use expr::{Quantity, Variables};
+use registry::MatchKind;
+pub use registry::UnitRegistry;
+use units::{DEFAULT_PRECISION, MAX_PRECISION};
+use utils::{format_number, parse_precision};
+
+lazy_static! {
+    // the registry of the built-in units, used by convert and convert_with
+    static ref DEFAULT_REGISTRY: UnitRegistry = UnitRegistry::default();
+}
+
+// a single conversion result (one per source/target ratio variant combination)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversionResult {
+    // the source ratio variant label (only set if the source has multiple variants)
+    pub source_variant: Option<String>,
+    // the target ratio variant label (only set if the target has multiple variants)
+    pub target_variant: Option<String>,
+    pub value: f64,
+    // the exact value (the "exact" feature; None if it can't be exact, e.g. for a level in dBm)
+    #[cfg(feature = "exact")]
+    pub exact_value: Option<Rational>,
+}
+
+// the structured outcome of a conversion
+pub struct Conversion {
+    // the unit type of the conversion (or the dimension if there is no matching unit type, e.g. "L·T⁻¹")
+    pub unit_type: String,
+    // the display string of the source (e.g. "5 ft 10 in" or "(3 ft + 4 in) * 2")
+    pub source: String,
+    // the parsed source values and units (combined by unit and sorted, larger units first)
+    pub sources: Vec<(f64, String)>,
+    pub target: DerivedUnit,
+    pub precision: i32,
+    pub results: Vec<ConversionResult>,
+    // the converted quantity
+    pub quantity: Quantity,
+    // non-fatal errors (e.g. an invalid precision that was replaced with the default one)
+    pub warnings: Vec<ConvertError>,
+    // whether the results are formatted from their exact values (see UnitRegistry::set_exact)
+    #[cfg(feature = "exact")]
+    pub exact: bool,
+}
+
+impl Conversion {
+    // the formatted value of a result, with the precision of the conversion
+    pub fn format_value(&self, result: &ConversionResult) -> String {
+        #[cfg(feature = "exact")]
+        if let Some(value) = result.exact_value.as_ref().filter(|_| self.exact) {
+            return exact::format(value, self.precision.min(MAX_PRECISION));
+        }
+        format_number(&result.value, Some(self.precision))
+    }
+
+    // the display lines of the results, as printed by the CLI
+    pub fn lines(&self) -> Vec<String> {
+        self.results
+            .iter()
+            .map(|r| {
+                format!(
+                    "[{}] {}{} = {} {}{}",
+                    self.unit_type,
+                    self.source,
+                    r.source_variant
+                        .as_ref()
+                        .map(|l| format!(" ({})", l))
+                        .unwrap_or_default(),
+                    self.format_value(r),
+                    self.target.abbr,
+                    r.target_variant
+                        .as_ref()
+                        .map(|l| format!(" ({})", l))
+                        .unwrap_or_default()
+                )
+            })
+            .collect()
+    }
+}
+
+// convert the "<sv> <su> [<sv2> <su2> ...] =|to <tu> [:<dp>]" input with the built-in units
+pub fn convert(input: &str) -> Result<Conversion, ConvertError> {
+    DEFAULT_REGISTRY.convert(input)
+}
+
+// convert the input with the built-in units and the given variables (see UnitRegistry::convert_with)
+pub fn convert_with(input: &str, variables: &Variables) -> Result<Conversion, ConvertError> {
+    DEFAULT_REGISTRY.convert_with(input, variables)
+}
+
+impl UnitRegistry {
+    // convert the "<sv> <su> [<sv2> <su2> ...] =|to <tu> [:<dp>]" input
+    pub fn convert(&self, input: &str) -> Result<Conversion, ConvertError> {
+        self.convert_with(input, &Variables::new())
+    }
+
+    // convert the input, with the given variables available on the source side (e.g. "x * 2 to cm")
+    pub fn convert_with(
+        &self,
+        input: &str,
+        variables: &Variables,
+    ) -> Result<Conversion, ConvertError> {
+        let sep = if input.contains('=') {
+            "="
+        } else if input.contains(" to ") {
+            " to "
+        } else {
+            return Err(ConvertError::MissingSeparator);
+        };
+        let left_right: Vec<&str> = input.split(sep).map(|s| s.trim()).collect();
+        if left_right.len() != 2 {
+            return Err(ConvertError::MissingSeparator);
+        }
+
+        // parse the right side (target unit and precision)
+        let mut warnings = Vec::new();
+        let mut precision = DEFAULT_PRECISION;
+        let right = left_right[1];
+        let t_unit_str = match right.split_once(':') {
+            Some((target_unit, target_precision)) => {
+                // fall back to the max/default precision if the precision is not valid
+                precision = match parse_precision(target_precision.trim()) {
+                    Ok(p) => p,
+                    Err(e @ ConvertError::PrecisionTooHigh(_)) => {
+                        warnings.push(e);
+                        MAX_PRECISION
+                    }
+                    Err(e) => {
+                        warnings.push(e);
+                        DEFAULT_PRECISION
+                    }
+                };
+                target_unit.trim()
+            }
+            None => right,
+        };
+        let left = left_right[0];
+
+        // an ambiguous target unit (e.g. "m" is both a meter and a minute) is resolved by the source side:
+        // the conversion is tried with every candidate and only one of them may succeed
+        // (or only one of the exact matches, e.g. "S" is a siemens rather than a second)
+        let candidates = self.find_matches(t_unit_str, None);
+        if candidates.len() > 1 {
+            let candidate_names: Vec<String> = candidates
+                .iter()
+                .map(|(c, _)| format!("{} ({})", c.name, c.describe(self)))
+                .collect();
+            let mut conversions = Vec::new();
+            let mut mismatch = None;
+            let mut source_error = None;
+            for (candidate, kind) in candidates {
+                let unit_type = candidate.describe(self);
+                match self.convert_into(left, candidate, variables, precision, warnings.clone()) {
+                    Ok(conversion) => conversions.push((conversion, kind)),
+                    // the source fits another unit type than the candidate
+                    Err(ConvertError::DimensionMismatch { left, right }) if right == unit_type => {
+                        mismatch.get_or_insert(left);
+                    }
+                    // an error of the source itself (e.g. an unknown unit, or an ambiguous unit
+                    // that another candidate resolves)
+                    Err(e) => {
+                        source_error.get_or_insert(e);
+                    }
+                }
+            }
+            if conversions.is_empty() {
+                return Err(match (source_error, mismatch) {
+                    (Some(e), _) => e,
+                    (None, left) => ConvertError::AmbiguousUnitMismatch {
+                        left: left.unwrap_or_default(),
+                        unit: t_unit_str.to_string(),
+                        candidates: candidate_names,
+                    },
+                });
+            }
+            let is_exact = |kind: &MatchKind| *kind < MatchKind::Folded;
+            if conversions
+                .iter()
+                .filter(|(_, kind)| is_exact(kind))
+                .count()
+                == 1
+            {
+                conversions.retain(|(_, kind)| is_exact(kind));
+            }
+            // a source that fits several candidates too (e.g. "1.5 m to m") is resolved on its own,
+            // and the candidate of the same unit type is kept
+            if conversions.len() > 1 {
+                let source = left.split('@').next().unwrap_or(left);
+                if let Some(source_type) = self
+                    .evaluate(source, None, variables)
+                    .ok()
+                    .and_then(|expression| expression.quantity.unit_type)
+                {
+                    if conversions
+                        .iter()
+                        .filter(|(c, _)| c.unit_type == source_type)
+                        .count()
+                        == 1
+                    {
+                        conversions.retain(|(c, _)| c.unit_type == source_type);
+                    }
+                }
+            }
+            // otherwise the best match of the target unit is kept (e.g. "2 rad * 3 m to m" is in meters,
+            // as "m" is the abbreviation of a meter rather than an alias of a minute)
+            if let Some(best) = conversions.iter().map(|(_, kind)| *kind).min() {
+                if conversions.iter().filter(|(_, kind)| *kind == best).count() == 1 {
+                    conversions.retain(|(_, kind)| *kind == best);
+                }
+            }
+            if conversions.len() > 1 {
+                return Err(ConvertError::AmbiguousUnit {
+                    unit: t_unit_str.to_string(),
+                    candidates: conversions
+                        .iter()
+                        .map(|(c, _)| format!("{} ({})", c.target.name, c.unit_type))
+                        .collect(),
+                });
+            }
+            return Ok(conversions.pop().unwrap().0);
+        }
+
+        let t_unit = self.resolve_unit(t_unit_str, None)?;
+        self.convert_into(left, t_unit, variables, precision, warnings)
+    }
+
+    // convert the left side of the input (source values and units, or an arithmetic expression of them)
+    // into the target unit
+    fn convert_into(
+        &self,
+        left: &str,
+        t_unit: DerivedUnit,
+        variables: &Variables,
+        precision: i32,
+        warnings: Vec<ConvertError>,
+    ) -> Result<Conversion, ConvertError> {
+        // a factor after "@" (e.g. the nominal voltage in "5000 mAh @ 3.7 V to W⋅h") bridges the dimensions
+        // of the source and the target: the source is multiplied or divided by it
+        let (source, factor) = match left.split_once('@') {
+            Some((source, factor)) => {
+                (source.trim(), Some(self.evaluate(factor, None, variables)?))
+            }
+            None => (left, None),
+        };
+        let expression = self.evaluate(source, t_unit.unit_type.as_deref(), variables)?;
+        let mut quantity = expression.quantity;
+        let mut display = expression.display;
+        if let Some(factor) = factor {
+            if quantity.dimension != t_unit.dimension {
+                quantity = match quantity.mul(&factor.quantity, self) {
+                    Some(product) if product.dimension == t_unit.dimension => product,
+                    _ => quantity.div(&factor.quantity).ok_or_else(|| {
+                        ConvertError::ExponentOutOfRange(format!(
+                            "{} @ {}",
+                            display, factor.display
+                        ))
+                    })?,
+                };
+            }
+            display = format!("{} @ {}", display, factor.display);
+        }
+        // a quantity of the inverse dimension is converted through its reciprocal
+        // (e.g. a fuel consumption in L/100km into a fuel economy in mpg, or a pace in s/m into m/s)
+        if quantity.dimension != t_unit.dimension && !quantity.dimension.is_dimensionless() {
+            if let Some(recip) = quantity
+                .recip()
+                .filter(|recip| recip.dimension == t_unit.dimension)
+            {
+                // (0 has no reciprocal, e.g. 0 mph in s/m)
+                if recip.values.values().any(|value| value.is_infinite()) {
+                    return Err(ConvertError::ValueOutOfRange {
+                        value: display,
+                        unit: t_unit.abbr,
+                    });
+                }
+                quantity = recip;
+            }
+        }
+        let unit_types_match = match (&quantity.unit_type, &t_unit.unit_type) {
+            (Some(s), Some(t)) => self.unit_types_match(s, t),
+            _ => true,
+        };
+        if quantity.dimension != t_unit.dimension || !unit_types_match {
+            return Err(ConvertError::DimensionMismatch {
+                left: quantity.describe(self),
+                right: t_unit.describe(self),
+            });
+        }
+        let multiple_source_units = quantity.values.len() > 1;
+        let multiple_target_units = t_unit.ratios.len() > 1;
+        if !quantity
+            .values
+            .values()
+            .all(|total| t_unit.has_value(*total))
+        {
+            return Err(ConvertError::ValueOutOfRange {
+                value: display,
+                unit: t_unit.abbr,
+            });
+        }
+        let mut results = Vec::new();
+        for (source_label, total) in quantity.values.iter() {
+            for (target_label, target_ratio) in t_unit.ratios.iter() {
+                results.push(ConversionResult {
+                    source_variant: if multiple_source_units {
+                        Some(source_label.clone())
+                    } else {
+                        None
+                    },
+                    target_variant: if multiple_target_units {
+                        Some(target_label.clone())
+                    } else {
+                        None
+                    },
+                    value: t_unit.from_base(*total, *target_ratio),
+                    #[cfg(feature = "exact")]
+                    exact_value: quantity
+                        .exact_values
+                        .as_ref()
+                        .and_then(|values| values.get(source_label))
+                        .and_then(|value| t_unit.from_base_exact(value, target_label)),
+                });
+            }
+        }
+        // (of two equivalent unit types, the one that is equivalent to the other, e.g. THROUGHPUT for DATA RATE)
+        let unit_types = [t_unit.unit_type.as_deref(), quantity.unit_type.as_deref()];
+        let unit_type = self.describe(
+            unit_types
+                .iter()
+                .flatten()
+                .find(|unit_type| self.equivalent_type(unit_type).is_some())
+                .or(unit_types.iter().flatten().next())
+                .copied(),
+            &t_unit.dimension,
+        );
+
+        Ok(Conversion {
+            unit_type,
+            source: display,
+            sources: expression.sources,
+            target: t_unit,
+            precision,
+            results,
+            quantity,
+            warnings,
+            #[cfg(feature = "exact")]
+            exact: self.is_exact(),
+        })
+    }
+}
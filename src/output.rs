@@ -0,0 +1,141 @@
+use std::fmt::Write;
+use std::str::FromStr;
+
+use crate::expr::display_pairs;
+use crate::Conversion;
+
+// the output format of the conversion results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    // "[TYPE] source (variant) = value abbr (variant)" lines
+    Text,
+    // a JSON object per result, one per line (JSON Lines)
+    Json,
+    // tab-separated values, one row per result (see TSV_HEADER)
+    Tsv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "tsv" => Ok(OutputFormat::Tsv),
+            _ => Err(format!(
+                "[ Unknown output format: {} (use text, json or tsv) ]",
+                s
+            )),
+        }
+    }
+}
+
+// the column names of the TSV output
+pub const TSV_HEADER: &str = "category\tsource\tsources\tsource_variant\ttarget_abbr\ttarget_name\ttarget_variant\tvalue\tformatted";
+
+// a JSON string literal
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+// a JSON number (or null if the value isn't finite, which JSON can't represent)
+fn json_number(value: f64) -> String {
+    if value.is_finite() {
+        // (the Debug format is the shortest representation that round-trips, e.g. "1.5" or "1e-9")
+        format!("{:?}", value)
+    } else {
+        "null".to_string()
+    }
+}
+
+fn json_optional_string(s: &Option<String>) -> String {
+    s.as_deref().map(json_string).unwrap_or("null".to_string())
+}
+
+// a TSV field (tabs and line breaks would break the columns/rows)
+fn tsv_field(s: &str) -> String {
+    s.replace(['\t', '\n', '\r'], " ")
+}
+
+impl Conversion {
+    // the results as JSON objects (one per result)
+    pub fn json_records(&self) -> Vec<String> {
+        let sources = self
+            .sources
+            .iter()
+            .map(|(value, unit)| {
+                format!(
+                    "{{\"value\":{},\"unit\":{}}}",
+                    json_number(*value),
+                    json_string(unit)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(",");
+        self.results
+            .iter()
+            .map(|r| {
+                format!(
+                    "{{\"category\":{},\"source\":{},\"sources\":[{}],\"source_variant\":{},\"target_abbr\":{},\"target_name\":{},\"target_variant\":{},\"value\":{},\"formatted\":{}}}",
+                    json_string(&self.unit_type),
+                    json_string(&self.source),
+                    sources,
+                    json_optional_string(&r.source_variant),
+                    json_string(&self.target.abbr),
+                    json_string(&self.target.name),
+                    json_optional_string(&r.target_variant),
+                    json_number(r.value),
+                    json_string(&self.format_value(r))
+                )
+            })
+            .collect()
+    }
+
+    // the results as TSV rows (one per result, with the TSV_HEADER columns)
+    pub fn tsv_records(&self) -> Vec<String> {
+        self.results
+            .iter()
+            .map(|r| {
+                [
+                    self.unit_type.clone(),
+                    self.source.clone(),
+                    display_pairs(&self.sources),
+                    r.source_variant.clone().unwrap_or_default(),
+                    self.target.abbr.clone(),
+                    self.target.name.clone(),
+                    r.target_variant.clone().unwrap_or_default(),
+                    format!("{:?}", r.value),
+                    self.format_value(r),
+                ]
+                .iter()
+                .map(|field| tsv_field(field))
+                .collect::<Vec<String>>()
+                .join("\t")
+            })
+            .collect()
+    }
+
+    // the results in the given output format
+    pub fn records(&self, format: OutputFormat) -> Vec<String> {
+        match format {
+            OutputFormat::Text => self.lines(),
+            OutputFormat::Json => self.json_records(),
+            OutputFormat::Tsv => self.tsv_records(),
+        }
+    }
+}
@@ -0,0 +1,662 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use regex::Regex;
+
+use crate::dimension::{DerivedUnit, Dimension};
+use crate::error::ConvertError;
+use crate::units::{
+    builtin_difference_types, builtin_product_types, builtin_units, Prefix, Scale, Unit,
+};
+
+lazy_static! {
+    // a unit with an exponent, e.g. "m^2", "s^-1", "s²", "m⁻¹" or "s2"
+    static ref UNIT_EXPONENT_REGEX: Regex = Regex::new(r"^(.*?)(?:\^(-?\d+)|([⁻]?[⁰¹²³⁴⁵⁶⁷⁸⁹]+)|(\d))$").unwrap();
+}
+
+// the max absolute value of a unit exponent (e.g. "m^9")
+const MAX_EXPONENT: i8 = 9;
+
+// a set of unit types (categories) and their units, in lookup order:
+// units are looked up, parsed and converted through a registry
+// (UnitRegistry::default() has the built-in units, UnitRegistry::new() starts empty)
+#[derive(Debug, Clone)]
+pub struct UnitRegistry {
+    unit_types: IndexMap<String, Vec<Arc<Unit>>>,
+    // the lookup index of the units
+    index: UnitIndex,
+    // the unit types of the differences of unit types with an arbitrary zero point
+    // (e.g. "TEMPERATURE DIFFERENCE" for "TEMPERATURE")
+    difference_types: IndexMap<String, String>,
+    // the unit types that are products of two unit types, with their factors
+    // (e.g. "TORQUE" for "FORCE" and "LENGTH")
+    product_types: IndexMap<String, (String, String)>,
+    // the unit types that are equivalent to another unit type of their dimension
+    // (e.g. a "THROUGHPUT" of a units file with the dimension "B/s" for "DATA RATE")
+    equivalent_types: IndexMap<String, String>,
+    // the unit types of the custom base dimensions in use (e.g. "STORY POINTS" of a units file),
+    // by the index of their dimension (see Dimension::custom)
+    custom_dimensions: Vec<String>,
+    // whether the conversion results are formatted from their exact values (the "exact" feature, see set_exact)
+    #[cfg(feature = "exact")]
+    exact: bool,
+}
+
+// a match of a unit string in the lookup index, in the order of precedence within a unit type:
+// an exact abbreviation, then an exact prefixed unit (unless the unit string is a known unit,
+// e.g. "am" is an arcminute, not an attometer), then an exact alias, then a case-insensitive abbreviation or alias
+// (e.g. to recognize "B" as bytes and "b" as bits, and "mHz" as millihertz and not megahertz),
+// then a prefixed name (the same order ranks the matches of different unit types, e.g. "d" is the abbreviation
+// of a day rather than an alias of a degree)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum MatchKind {
+    Abbr,
+    Prefixed,
+    Alias,
+    Folded,
+    PrefixedName,
+}
+
+// a unit of the index: the position of its unit type and its position in the unit type, with an optional prefix
+#[derive(Debug, Clone, Copy)]
+struct IndexEntry {
+    unit_type: usize,
+    position: usize,
+    prefix: Option<&'static Prefix>,
+    kind: MatchKind,
+}
+
+// the lookup index of the registry: the matches of every unit string, keyed once by the exact string
+// and once by the lowercase one (every unit type keeps its first unit of each kind in the unit type order,
+// the same priority as a scan of the units)
+#[derive(Debug, Clone, Default)]
+struct UnitIndex {
+    // the abbreviations and aliases, and the prefixed abbreviations (e.g. "kPa" or "µm") and the symbols that
+    // take the prefixes (matched case-sensitively, e.g. "Bps" for bytes and "b/s" for bits per second)
+    exact: HashMap<String, Vec<IndexEntry>>,
+    // the lowercase abbreviations and aliases, and the lowercase prefixed names and aliases
+    // (e.g. "kilopascal" or "mebibytes")
+    folded: HashMap<String, Vec<IndexEntry>>,
+}
+
+impl UnitIndex {
+    fn add(map: &mut HashMap<String, Vec<IndexEntry>>, key: String, entry: IndexEntry) {
+        let entries = map.entry(key).or_default();
+        if !entries
+            .iter()
+            .any(|e| e.unit_type == entry.unit_type && e.kind == entry.kind)
+        {
+            entries.push(entry);
+        }
+    }
+
+    // index a unit that comes after all the indexed units of its unit type
+    fn insert(&mut self, unit_type: usize, position: usize, unit: &Unit) {
+        let entry = |kind, prefix| IndexEntry {
+            unit_type,
+            position,
+            prefix,
+            kind,
+        };
+        Self::add(
+            &mut self.exact,
+            unit.abbr.clone(),
+            entry(MatchKind::Abbr, None),
+        );
+        for unit_symbol in unit.prefixes.symbols() {
+            Self::add(
+                &mut self.exact,
+                unit_symbol.clone(),
+                entry(MatchKind::Prefixed, None),
+            );
+        }
+        for prefix in unit.prefixes.prefixes() {
+            for symbol in prefix.symbols.iter() {
+                for unit_symbol in unit.prefixes.symbols() {
+                    Self::add(
+                        &mut self.exact,
+                        format!("{}{}", symbol, unit_symbol),
+                        entry(MatchKind::Prefixed, Some(prefix)),
+                    );
+                }
+            }
+        }
+        for alias in unit.aliases.iter() {
+            Self::add(
+                &mut self.exact,
+                alias.clone(),
+                entry(MatchKind::Alias, None),
+            );
+        }
+        for symbol in std::iter::once(&unit.abbr).chain(unit.aliases.iter()) {
+            Self::add(
+                &mut self.folded,
+                symbol.to_lowercase(),
+                entry(MatchKind::Folded, None),
+            );
+        }
+        for prefix in unit.prefixes.prefixes() {
+            for unit_name in std::iter::once(&unit.name).chain(unit.aliases.iter()) {
+                Self::add(
+                    &mut self.folded,
+                    format!("{}{}", prefix.name, unit_name.to_lowercase()),
+                    entry(MatchKind::PrefixedName, Some(prefix)),
+                );
+            }
+        }
+    }
+}
+
+impl Default for UnitRegistry {
+    fn default() -> UnitRegistry {
+        let mut registry = UnitRegistry::new();
+        for (unit_type, units) in builtin_units() {
+            for unit in units {
+                registry.add_unit(unit_type, unit);
+            }
+        }
+        for (unit_type, difference_type) in builtin_difference_types() {
+            registry.add_difference_type(unit_type, difference_type);
+        }
+        for (unit_type, left, right) in builtin_product_types() {
+            registry.add_product_type(unit_type, left, right);
+        }
+        registry
+    }
+}
+
+impl UnitRegistry {
+    // an empty registry
+    pub fn new() -> UnitRegistry {
+        UnitRegistry {
+            unit_types: IndexMap::new(),
+            index: UnitIndex::default(),
+            difference_types: IndexMap::new(),
+            product_types: IndexMap::new(),
+            equivalent_types: IndexMap::new(),
+            custom_dimensions: Vec::new(),
+            #[cfg(feature = "exact")]
+            exact: false,
+        }
+    }
+
+    // format the conversion results from their exact (rational) values rather than the floating point ones,
+    // when they have one (e.g. not for a level in dBm)
+    #[cfg(feature = "exact")]
+    pub fn set_exact(&mut self, exact: bool) -> &mut Self {
+        self.exact = exact;
+        self
+    }
+
+    #[cfg(feature = "exact")]
+    pub fn is_exact(&self) -> bool {
+        self.exact
+    }
+
+    // the name of a unit type of the registry (unit types are matched case-insensitively)
+    fn unit_type_name(&self, unit_type: &str) -> Option<&str> {
+        self.unit_types
+            .keys()
+            .find(|t| t.eq_ignore_ascii_case(unit_type))
+            .map(|t| t.as_str())
+    }
+
+    fn unit_type_mut(&mut self, unit_type: &str) -> Result<&mut Vec<Arc<Unit>>, ConvertError> {
+        let name = self
+            .unit_type_name(unit_type)
+            .ok_or_else(|| ConvertError::UnknownUnitType(unit_type.to_string()))?
+            .to_string();
+        Ok(self.unit_types.get_mut(&name).unwrap())
+    }
+
+    // add a (new, empty) unit type
+    pub fn add_unit_type(&mut self, unit_type: &str) -> &mut Self {
+        if self.unit_type_name(unit_type).is_none() {
+            self.unit_types.insert(unit_type.to_string(), Vec::new());
+        }
+        self
+    }
+
+    // add a unit to a unit type (which is added if it doesn't exist)
+    pub fn add_unit(&mut self, unit_type: &str, unit: Unit) -> &mut Self {
+        self.add_unit_type(unit_type);
+        let name = self.unit_type_name(unit_type).unwrap().to_string();
+        let (position, _, units) = self.unit_types.get_full_mut(&name).unwrap();
+        self.index.insert(position, units.len(), &unit);
+        units.push(Arc::new(unit));
+        self
+    }
+
+    // add an alias to the unit with the given abbreviation
+    pub fn add_alias(
+        &mut self,
+        unit_type: &str,
+        abbr: &str,
+        alias: &str,
+    ) -> Result<&mut Self, ConvertError> {
+        let units = self.unit_type_mut(unit_type)?;
+        let unit = units
+            .iter_mut()
+            .find(|u| u.abbr == abbr)
+            .ok_or_else(|| ConvertError::UnknownUnit(abbr.to_string()))?;
+        Arc::make_mut(unit).aliases.push(alias.to_string());
+        self.reindex();
+        Ok(self)
+    }
+
+    // remove the unit with the given abbreviation
+    pub fn remove_unit(&mut self, unit_type: &str, abbr: &str) -> Result<&mut Self, ConvertError> {
+        let units = self.unit_type_mut(unit_type)?;
+        let position = units
+            .iter()
+            .position(|u| u.abbr == abbr)
+            .ok_or_else(|| ConvertError::UnknownUnit(abbr.to_string()))?;
+        units.remove(position);
+        self.reindex();
+        Ok(self)
+    }
+
+    // remove a unit type with all its units
+    pub fn remove_unit_type(&mut self, unit_type: &str) -> Result<&mut Self, ConvertError> {
+        let name = self
+            .unit_type_name(unit_type)
+            .ok_or_else(|| ConvertError::UnknownUnitType(unit_type.to_string()))?
+            .to_string();
+        self.unit_types.shift_remove(&name);
+        self.reindex();
+        Ok(self)
+    }
+
+    // keep only the given unit types (e.g. to restrict the registry to LENGTH and MASS)
+    pub fn retain_unit_types(&mut self, unit_types: &[&str]) -> &mut Self {
+        self.unit_types
+            .retain(|t, _| unit_types.iter().any(|ut| t.eq_ignore_ascii_case(ut)));
+        self.reindex();
+        self
+    }
+
+    // set the unit type of the differences of a unit type (e.g. "TEMPERATURE DIFFERENCE" for "TEMPERATURE"):
+    // the values of the unit type are then absolute (e.g. 20 °C), a difference can be added to them
+    // or subtracted from them, and the difference of two of them is a value of the difference type
+    pub fn add_difference_type(&mut self, unit_type: &str, difference_type: &str) -> &mut Self {
+        self.difference_types
+            .insert(unit_type.to_string(), difference_type.to_string());
+        self
+    }
+
+    // the unit type of the differences of a unit type, if any
+    pub fn difference_type(&self, unit_type: &str) -> Option<&str> {
+        self.difference_types
+            .iter()
+            .find(|(t, _)| t.eq_ignore_ascii_case(unit_type))
+            .map(|(_, difference_type)| difference_type.as_str())
+    }
+
+    // set a unit type as equivalent to another one of the same dimension: their units convert into each other
+    // (unlike other unit types that share a dimension, e.g. TORQUE and ENERGY)
+    pub fn add_equivalent_type(&mut self, unit_type: &str, equivalent_type: &str) -> &mut Self {
+        self.equivalent_types
+            .insert(unit_type.to_string(), equivalent_type.to_string());
+        self
+    }
+
+    // the unit type that a unit type is equivalent to, if any
+    pub fn equivalent_type(&self, unit_type: &str) -> Option<&str> {
+        self.equivalent_types
+            .iter()
+            .find(|(t, _)| t.eq_ignore_ascii_case(unit_type))
+            .map(|(_, equivalent_type)| equivalent_type.as_str())
+    }
+
+    // whether the values of two unit types convert into each other (the same or equivalent unit types)
+    pub(crate) fn unit_types_match(&self, left: &str, right: &str) -> bool {
+        let left = self.equivalent_type(left).unwrap_or(left);
+        let right = self.equivalent_type(right).unwrap_or(right);
+        left.eq_ignore_ascii_case(right)
+    }
+
+    // set a unit type as the product of two unit types (e.g. "TORQUE" for "FORCE" and "LENGTH"):
+    // a product of other unit types with its dimension is of another unit type of the dimension
+    // (e.g. "kW⋅h" is an ENERGY rather than a TORQUE)
+    pub fn add_product_type(&mut self, unit_type: &str, left: &str, right: &str) -> &mut Self {
+        self.product_types
+            .insert(unit_type.to_string(), (left.to_string(), right.to_string()));
+        self
+    }
+
+    // the unit type of a product of two unit types, if its dimension is the one of a product type
+    // and the factors tell the unit type (e.g. ENERGY for a POWER and a TIME,
+    // but TORQUE or ENERGY for a FORCE and a LENGTH)
+    pub(crate) fn product_unit_type(
+        &self,
+        left: Option<&str>,
+        right: Option<&str>,
+        dimension: &Dimension,
+    ) -> Option<String> {
+        let (left, right) = (left?, right?);
+        let product_factors = |unit_type: &str| {
+            self.product_types
+                .iter()
+                .find(|(t, _)| t.eq_ignore_ascii_case(unit_type))
+                .map(|(_, factors)| factors)
+        };
+        let unit_types: Vec<&str> = self
+            .unit_types()
+            .filter(|(_, units)| units.iter().any(|u| u.dimension == *dimension))
+            .map(|(unit_type, _)| unit_type)
+            .collect();
+        if !unit_types.iter().any(|t| product_factors(t).is_some()) {
+            return None;
+        }
+        let mut candidates = unit_types.into_iter().filter(|t| match product_factors(t) {
+            Some((l, r)) => {
+                (l.eq_ignore_ascii_case(left) && r.eq_ignore_ascii_case(right))
+                    || (l.eq_ignore_ascii_case(right) && r.eq_ignore_ascii_case(left))
+            }
+            None => true,
+        });
+        match (candidates.next(), candidates.next()) {
+            (Some(unit_type), None) => Some(unit_type.to_string()),
+            _ => None,
+        }
+    }
+
+    // the unit types and their units
+    pub fn unit_types(&self) -> impl Iterator<Item = (&str, &[Arc<Unit>])> {
+        self.unit_types
+            .iter()
+            .map(|(unit_type, units)| (unit_type.as_str(), units.as_slice()))
+    }
+
+    // rebuild the lookup index after units were changed or removed
+    fn reindex(&mut self) {
+        self.index = UnitIndex::default();
+        for (unit_type, units) in self.unit_types.values().enumerate() {
+            for (position, unit) in units.iter().enumerate() {
+                self.index.insert(unit_type, position, unit);
+            }
+        }
+    }
+
+    // find all the units matching a unit string, at most one per unit type, in the unit types order
+    // (e.g. "m" is both a meter in LENGTH and a minute in TIME)
+    pub fn find_units(&self, unit_str: &str, unit_type: Option<&str>) -> Vec<DerivedUnit> {
+        self.find_matches(unit_str, unit_type)
+            .into_iter()
+            .map(|(unit, _)| unit)
+            .collect()
+    }
+
+    // the units matching a unit string, with the kind of their match
+    // (the best match of each unit type, see MatchKind)
+    pub(crate) fn find_matches(
+        &self,
+        unit_str: &str,
+        unit_type: Option<&str>,
+    ) -> Vec<(DerivedUnit, MatchKind)> {
+        let in_unit_type = |entry: &&IndexEntry| {
+            unit_type.is_none_or(|ut| {
+                self.unit_types
+                    .get_index(entry.unit_type)
+                    .is_some_and(|(u_type, _)| u_type.eq_ignore_ascii_case(ut))
+            })
+        };
+        let exact = self.index.exact.get(unit_str).map(Vec::as_slice);
+        let folded = self
+            .index
+            .folded
+            .get(&unit_str.to_lowercase())
+            .map(Vec::as_slice);
+        let entries = || {
+            exact
+                .unwrap_or_default()
+                .iter()
+                .chain(folded.unwrap_or_default())
+                .filter(in_unit_type)
+        };
+        // an exact abbreviation or alias of any unit takes precedence over a prefixed unit
+        // (e.g. "am" is an arcminute, not an attometer)
+        let is_known_unit = entries().any(|e| matches!(e.kind, MatchKind::Abbr | MatchKind::Alias));
+        let mut matches: Vec<&IndexEntry> = Vec::new();
+        for entry in entries().filter(|e| !(is_known_unit && e.kind == MatchKind::Prefixed)) {
+            match matches.iter_mut().find(|m| m.unit_type == entry.unit_type) {
+                Some(m) if entry.kind < m.kind => *m = entry,
+                Some(_) => {}
+                None => matches.push(entry),
+            }
+        }
+        matches.sort_by_key(|entry| entry.unit_type);
+        matches
+            .into_iter()
+            .map(|entry| {
+                let (u_type, units) = self.unit_types.get_index(entry.unit_type).unwrap();
+                let unit = &units[entry.position];
+                let derived = match entry.prefix {
+                    Some(prefix) => DerivedUnit::prefixed(u_type, prefix, unit),
+                    None => DerivedUnit::from_unit(u_type, unit),
+                };
+                #[cfg(feature = "exact")]
+                let derived = if self.exact {
+                    derived.with_exact_ratios(unit, entry.prefix)
+                } else {
+                    derived
+                };
+                (derived, entry.kind)
+            })
+            .collect()
+    }
+
+    // the matches of a unit string of the best kind across the unit types (see MatchKind),
+    // e.g. the day for "d" (an abbreviation) rather than the degree (an alias)
+    fn find_best_matches(&self, unit_str: &str, unit_type: Option<&str>) -> Vec<DerivedUnit> {
+        let matches = self.find_matches(unit_str, unit_type);
+        let best = matches.iter().map(|(_, kind)| *kind).min();
+        matches
+            .into_iter()
+            .filter(|(_, kind)| Some(*kind) == best)
+            .map(|(unit, _)| unit)
+            .collect()
+    }
+
+    // find a unit or a prefixed one (e.g. "kPa") and return it as a DerivedUnit
+    // (if the unit string is ambiguous, the best match, e.g. "A" is an ampere and "a" an are,
+    // and the first one in the unit types order if several unit types match as well, see resolve_unit)
+    pub fn find_unit(&self, unit_str: &str, unit_type: Option<&str>) -> Option<DerivedUnit> {
+        self.find_best_matches(unit_str, unit_type)
+            .into_iter()
+            .next()
+    }
+
+    // find a unit, preferably in the given unit type (e.g. "m" is a minute in TIME but a meter otherwise),
+    // or an ambiguous unit error if the best matches are of several unit types
+    // (e.g. "c" is both the abbreviation of the speed of light and of a cup)
+    fn find_unit_preferring(
+        &self,
+        unit_str: &str,
+        unit_type: Option<&str>,
+    ) -> Result<Option<DerivedUnit>, ConvertError> {
+        if let Some(unit) = unit_type.and_then(|_| self.find_unit(unit_str, unit_type)) {
+            return Ok(Some(unit));
+        }
+        let mut matches = self.find_best_matches(unit_str, None);
+        if matches.len() > 1 {
+            return Err(ConvertError::AmbiguousUnit {
+                unit: unit_str.to_string(),
+                candidates: matches
+                    .iter()
+                    .map(|unit| format!("{} ({})", unit.name, unit.describe(self)))
+                    .collect(),
+            });
+        }
+        Ok(matches.pop())
+    }
+
+    // resolve a single unit with an optional exponent (e.g. "s²" or "m^3")
+    fn resolve_unit_power(
+        &self,
+        unit_str: &str,
+        unit_type: Option<&str>,
+    ) -> Result<DerivedUnit, ConvertError> {
+        let unit_str = unit_str.trim();
+        if let Some(unit) = self.find_unit_preferring(unit_str, unit_type)? {
+            return Ok(unit);
+        }
+        let unknown_unit = || ConvertError::UnknownUnit(unit_str.to_string());
+        let captures = UNIT_EXPONENT_REGEX
+            .captures(unit_str)
+            .ok_or_else(unknown_unit)?;
+        let unit = match captures.get(1) {
+            Some(u) => self.find_unit_preferring(u.as_str().trim(), unit_type)?,
+            None => None,
+        }
+        .filter(|unit| unit.scale == Scale::Linear)
+        .ok_or_else(unknown_unit)?;
+        let exponent = match captures.get(2).or_else(|| captures.get(4)) {
+            Some(e) => e.as_str().parse::<i8>().ok(),
+            None => captures.get(3).and_then(|e| parse_superscript(e.as_str())),
+        }
+        .filter(|e| e.abs() <= MAX_EXPONENT)
+        .ok_or_else(|| ConvertError::ExponentOutOfRange(unit_str.to_string()))?;
+        unit.pow(exponent)
+            .ok_or_else(|| ConvertError::ExponentOutOfRange(unit_str.to_string()))
+    }
+
+    // resolve a product of units (e.g. "kg⋅m" or "N*m")
+    fn resolve_unit_product(
+        &self,
+        unit_str: &str,
+        unit_type: Option<&str>,
+    ) -> Result<DerivedUnit, ConvertError> {
+        if let Some(unit) = self.find_unit_preferring(unit_str.trim(), unit_type)? {
+            return Ok(unit);
+        }
+        let mut product: Option<DerivedUnit> = None;
+        for factor_str in unit_str.split(['*', '·', '⋅']) {
+            let factor = self.resolve_unit_power(factor_str, unit_type)?;
+            // (inverse and decibel units, e.g. "min/km" or "dBm", can't be composed)
+            if factor.scale != Scale::Linear {
+                return Err(ConvertError::UnknownUnit(factor_str.trim().to_string()));
+            }
+            product = Some(match product {
+                Some(p) => {
+                    let mut product = p.mul(&factor).ok_or_else(|| {
+                        ConvertError::ExponentOutOfRange(unit_str.trim().to_string())
+                    })?;
+                    product.unit_type = self.product_unit_type(
+                        p.unit_type.as_deref(),
+                        factor.unit_type.as_deref(),
+                        &product.dimension,
+                    );
+                    product
+                }
+                None => factor,
+            });
+        }
+        product.ok_or_else(|| ConvertError::UnknownUnit(unit_str.trim().to_string()))
+    }
+
+    // resolve a unit string into a unit: either a unit of the registry (preferably of the given unit type),
+    // or a product/quotient of units with optional exponents (e.g. "m/s", "kg⋅m/s²", "kW⋅h/day" or "W/m^2");
+    // all the units after a "/" are in the denominator (e.g. "J/kg⋅K" is "J/(kg⋅K)")
+    pub fn resolve_unit(
+        &self,
+        unit_str: &str,
+        unit_type: Option<&str>,
+    ) -> Result<DerivedUnit, ConvertError> {
+        let unit_str = unit_str.trim();
+        if let Some(unit) = self.find_unit_preferring(unit_str, unit_type)? {
+            return Ok(unit);
+        }
+        let out_of_range = || ConvertError::ExponentOutOfRange(unit_str.to_string());
+        // (the unknown unit is reported as a whole, e.g. "m/smoot")
+        let resolve_product =
+            |product_str: &str| match self.resolve_unit_product(product_str, unit_type) {
+                Err(ConvertError::UnknownUnit(_)) => {
+                    Err(ConvertError::UnknownUnit(unit_str.to_string()))
+                }
+                result => result,
+            };
+        let mut groups = unit_str.split('/');
+        let numerator_str = groups.next().unwrap_or_default().trim();
+        let mut unit = if numerator_str == "1" {
+            DerivedUnit::one()
+        } else {
+            resolve_product(numerator_str)?
+        };
+        for denominator_str in groups {
+            unit = unit
+                .div(&resolve_product(denominator_str)?)
+                .ok_or_else(out_of_range)?;
+        }
+        Ok(unit)
+    }
+
+    // find the unit type of a dimension (e.g. "AREA" for L²), if any
+    pub fn find_unit_type(&self, dimension: &Dimension) -> Option<&str> {
+        if dimension.is_dimensionless() {
+            return None;
+        }
+        self.unit_types()
+            .find(|(_, units)| units.iter().any(|u| u.dimension == *dimension))
+            .map(|(unit_type, _)| unit_type)
+    }
+
+    // describe a unit type (if known) or a dimension, e.g. "LENGTH", "AREA" for L², "L·T⁻¹" or "NUMBER"
+    pub fn describe(&self, unit_type: Option<&str>, dimension: &Dimension) -> String {
+        match unit_type.or_else(|| self.find_unit_type(dimension)) {
+            Some(unit_type) => unit_type.to_string(),
+            None if dimension.is_dimensionless() => "NUMBER".to_string(),
+            None => dimension.describe(&self.custom_dimensions),
+        }
+    }
+
+    // the custom base dimension of a unit type, given the new unit types that are not added yet
+    // (see add_custom_dimension), or None if all of them are in use
+    pub(crate) fn custom_dimension(
+        &self,
+        unit_type: &str,
+        new_types: &[String],
+    ) -> Option<Dimension> {
+        let index = match self.custom_dimensions.iter().position(|n| n == unit_type) {
+            Some(index) => index,
+            None => {
+                self.custom_dimensions.len()
+                    + new_types
+                        .iter()
+                        .position(|n| n == unit_type)
+                        .unwrap_or(new_types.len())
+            }
+        };
+        Dimension::custom(index)
+    }
+
+    // add the custom base dimension of a new unit type (the next one, see custom_dimension)
+    pub(crate) fn add_custom_dimension(&mut self, unit_type: &str) {
+        if !self.custom_dimensions.iter().any(|n| n == unit_type) {
+            self.custom_dimensions.push(unit_type.to_string());
+        }
+    }
+}
+
+// parse a superscript exponent (e.g. "⁻²") into an integer
+fn parse_superscript(exponent_str: &str) -> Option<i8> {
+    let digits: String = exponent_str
+        .chars()
+        .map(|c| match c {
+            '⁻' => '-',
+            '⁰' => '0',
+            '¹' => '1',
+            '²' => '2',
+            '³' => '3',
+            '⁴' => '4',
+            '⁵' => '5',
+            '⁶' => '6',
+            '⁷' => '7',
+            '⁸' => '8',
+            '⁹' => '9',
+            c => c,
+        })
+        .collect();
+    digits.parse::<i8>().ok()
+}
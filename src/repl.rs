@@ -0,0 +1,243 @@
+use std::io::{self, BufRead, IsTerminal, Write};
+
+use cu::expr::Variables;
+use cu::units::{DEFAULT_PRECISION, MAX_PRECISION};
+use cu::utils::parse_precision;
+use cu::{ConvertError, UnitRegistry};
+use indexmap::IndexMap;
+use regex::Regex;
+
+lazy_static! {
+    // a variable assignment, e.g. "x = 3 ft 4 in"
+    static ref ASSIGNMENT_REGEX: Regex = Regex::new(r"^([A-Za-z_][A-Za-z0-9_]*)\s*=\s*(.+)$").unwrap();
+}
+
+// the name of the variable holding the last result
+const ANS: &str = "ans";
+
+fn help() {
+    println!("Enter a conversion (e.g. 5 ft 10 in to cm) or one of the following:");
+    println!("<name> = <expression>  - store a quantity in a variable (e.g. x = 3 ft 4 in, then x * 2 to cm)");
+    println!("ans                    - the result of the last conversion (e.g. ans to in)");
+    println!(
+        ":precision [<dp>]      - show or set the precision used for the following conversions"
+    );
+    println!(
+        ":units [<type>]        - list the supported units (of a unit type, e.g. :units LENGTH)"
+    );
+    println!(":vars                  - list the variables");
+    println!(":history               - list the previous lines (use !<n> to run line n again)");
+    println!(":help                  - print this help");
+    println!(":quit                  - exit (or Ctrl+D)");
+}
+
+// the state of an interactive session
+struct Session<'a> {
+    registry: &'a UnitRegistry,
+    variables: Variables,
+    // the display strings of the variables (e.g. "[LENGTH] 3 ft 4 in")
+    definitions: IndexMap<String, String>,
+    // the precision used for the conversions without an explicit one
+    precision: i32,
+    history: Vec<String>,
+}
+
+impl Session<'_> {
+    fn new(registry: &UnitRegistry) -> Session<'_> {
+        Session {
+            registry,
+            variables: Variables::new(),
+            definitions: IndexMap::new(),
+            precision: DEFAULT_PRECISION,
+            history: Vec::new(),
+        }
+    }
+
+    // evaluate a line and return false if the session should end
+    fn eval(&mut self, line: &str) -> bool {
+        // re-run a line from the history
+        if let Some(n) = line.strip_prefix('!') {
+            return match n
+                .trim()
+                .parse::<usize>()
+                .ok()
+                .and_then(|n| self.history.get(n.wrapping_sub(1)))
+            {
+                Some(previous) => {
+                    let previous = previous.clone();
+                    println!("{}", previous);
+                    self.eval(&previous)
+                }
+                None => {
+                    println!("[ Not in the history: {} ]", line);
+                    true
+                }
+            };
+        }
+        self.history.push(line.to_string());
+
+        if let Some(command) = line.strip_prefix(':') {
+            return self.command(command.trim());
+        }
+        if line == "quit" || line == "exit" {
+            return false;
+        }
+        if let Some(captures) = ASSIGNMENT_REGEX.captures(line) {
+            let name = captures.get(1).unwrap().as_str();
+            let expression_str = captures.get(2).unwrap().as_str();
+            if self.registry.find_unit(name, None).is_none() {
+                self.assign(name, expression_str);
+                return true;
+            }
+            // a unit on the left side is a conversion (e.g. "ft = m"), unless the right side is a quantity
+            if self
+                .registry
+                .evaluate(expression_str, None, &self.variables)
+                .is_ok()
+            {
+                println!("[ The unit '{}' can't be used as a variable name ]", name);
+                return true;
+            }
+        }
+        self.convert(line);
+        true
+    }
+
+    fn assign(&mut self, name: &str, expression_str: &str) {
+        match self
+            .registry
+            .evaluate(expression_str, None, &self.variables)
+        {
+            Ok(expression) => {
+                let definition = format!(
+                    "[{}] {}",
+                    expression.quantity.describe(self.registry),
+                    expression.display
+                );
+                println!("{} = {}", name, definition);
+                self.variables.insert(name.to_string(), expression.quantity);
+                self.definitions.insert(name.to_string(), definition);
+            }
+            Err(e) => println!("{}", e),
+        }
+    }
+
+    fn convert(&mut self, line: &str) {
+        // use the session precision unless the target has its own
+        // (a colon of the source is a time value, e.g. "5:30 h to min")
+        let target = match line.split_once('=') {
+            Some((_, target)) => target,
+            None => line.split_once(" to ").map(|(_, t)| t).unwrap_or_default(),
+        };
+        let input = if target.contains(':') {
+            line.to_string()
+        } else {
+            format!("{}:{}", line, self.precision)
+        };
+        match self.registry.convert_with(&input, &self.variables) {
+            Ok(conversion) => {
+                for warning in conversion.warnings.iter() {
+                    println!("{}", warning);
+                }
+                for line in conversion.lines() {
+                    println!("{}", line);
+                }
+                if let Some(result) = conversion.results.first() {
+                    self.definitions.insert(
+                        ANS.to_string(),
+                        format!(
+                            "[{}] {} {}",
+                            conversion.unit_type,
+                            conversion.format_value(result),
+                            conversion.target.abbr
+                        ),
+                    );
+                    self.variables
+                        .insert(ANS.to_string(), conversion.quantity.clone());
+                }
+            }
+            Err(e) => println!("{}", e),
+        }
+    }
+
+    // run a meta-command and return false if the session should end
+    fn command(&mut self, command: &str) -> bool {
+        let (name, arg) = match command.split_once(char::is_whitespace) {
+            Some((name, arg)) => (name, Some(arg.trim())),
+            None => (command, None),
+        };
+        match (name, arg) {
+            ("q" | "quit" | "exit", _) => return false,
+            ("help" | "h", _) => help(),
+            ("precision" | "p", None) => println!("Precision: {}", self.precision),
+            ("precision" | "p", Some(p)) => {
+                // fall back to the max/default precision if the precision is not valid
+                self.precision = match parse_precision(p) {
+                    Ok(p) => p,
+                    Err(e @ ConvertError::PrecisionTooHigh(_)) => {
+                        println!("{}", e);
+                        MAX_PRECISION
+                    }
+                    Err(e) => {
+                        println!("{}", e);
+                        DEFAULT_PRECISION
+                    }
+                };
+                println!("Precision: {}", self.precision);
+            }
+            ("units" | "u", unit_type) => {
+                if unit_type.is_none_or(|t| {
+                    self.registry
+                        .unit_types()
+                        .any(|u| u.0.eq_ignore_ascii_case(t))
+                }) {
+                    crate::supported_units(self.registry, unit_type);
+                } else {
+                    println!("[ Unknown unit type: {} ]", unit_type.unwrap_or_default());
+                }
+            }
+            ("vars" | "v", _) => {
+                for (name, definition) in self.definitions.iter() {
+                    println!("{} = {}", name, definition);
+                }
+            }
+            ("history", _) => {
+                for (i, line) in self.history.iter().enumerate() {
+                    println!("{:>4}  {}", i + 1, line);
+                }
+            }
+            _ => println!("[ Unknown command: :{} (type :help for help) ]", command),
+        }
+        true
+    }
+}
+
+// run the interactive mode (the prompt is only shown if the input is a terminal)
+pub fn run(registry: &UnitRegistry) {
+    let stdin = io::stdin();
+    let interactive = stdin.is_terminal();
+    if interactive {
+        crate::version();
+        println!("Type :help for help, :quit to exit");
+    }
+    let mut session = Session::new(registry);
+    let mut line = String::new();
+    loop {
+        if interactive {
+            print!("> ");
+            io::stdout().flush().unwrap();
+        }
+        line.clear();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if !session.eval(line) {
+            break;
+        }
+    }
+}
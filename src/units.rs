@@ -1,342 +1,927 @@
+use crate::constants::{
+    Definition, ASTRONOMICAL_UNIT, BTU, CALORIE, DAY, ELEMENTARY_CHARGE, FOOT, GREGORIAN_YEAR,
+    IMPERIAL_FLUID_OUNCE, IMPERIAL_GALLON, INCH, JULIAN_YEAR, MILE, NAUTICAL_MILE, PARSEC, PI,
+    PLANCK_LENGTH, POUND, POUND_FORCE, SPEED_OF_LIGHT, STANDARD_ATMOSPHERE, STANDARD_GRAVITY,
+    US_FLUID_OUNCE, US_GALLON, US_SURVEY_FOOT, YARD,
+};
+use crate::dimension::Dimension;
+
 pub const DEFAULT_PRECISION: i32 = 2;
 pub const MAX_PRECISION: i32 = 14;
 
+#[derive(Debug, Clone)]
 pub struct Unit {
+    pub name: String,
+    pub abbr: String,
+    pub aliases: Vec<String>,
+    // the dimension of the unit (the ratios are relative to the coherent SI unit of the dimension)
+    pub dimension: Dimension,
+    // the ratios per ratio variant label, with their exact definitions (see constants::Definition)
+    pub ratios: Vec<(String, Definition)>,
+    // the offset of the zero of the unit from the zero of the coherent SI unit, in the SI unit
+    // (only for units with an arbitrary zero point, e.g. a temperature in °C or a gauge pressure;
+    // applied to single values, not to deltas)
+    pub offset: Definition,
+    // how the values of the unit relate to the coherent SI unit of its dimension (see Scale)
+    pub scale: Scale,
+    // the prefixes the unit accepts (e.g. "k" for "kPa" or "Mi" for "MiB")
+    pub prefixes: Prefixes,
+}
+
+impl Unit {
+    // a unit with a single ratio (to the coherent SI unit of the dimension), e.g. for UnitRegistry::add_unit
+    pub fn new(name: &str, abbr: &str, dimension: Dimension, ratio: f64) -> Unit {
+        Unit {
+            name: name.to_string(),
+            abbr: abbr.to_string(),
+            aliases: Vec::new(),
+            dimension,
+            ratios: vec![("".to_string(), ratio.into())],
+            offset: Definition::ZERO,
+            scale: Scale::Linear,
+            prefixes: Prefixes::None,
+        }
+    }
+}
+
+// the scale of the values of a unit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Scale {
+    // the value in the coherent SI unit is the value times the ratio (plus the offset)
+    Linear,
+    // the unit measures the inverse of its dimension (e.g. a pace in min/km for a speed):
+    // the value in the coherent SI unit is the ratio divided by the value of the unit
+    Inverse,
+    // a level in decibels relative to a reference value (the ratio, e.g. 1 mW for dBm):
+    // the value in the coherent SI unit is the ratio times 10^(value/10)
+    Decibel,
+}
+
+// a unit prefix, e.g. "k" (kilo) or "Ki" (kibi)
+#[derive(Debug)]
+pub struct Prefix {
+    // the symbols of the prefix (the first one is used in the abbreviation of the prefixed unit)
+    pub symbols: &'static [&'static str],
     pub name: &'static str,
-    pub abbr: &'static str,
-    pub aliases: &'static [&'static str],
-    pub ratios: Option<Vec<(&'static str, f64)>>,
-    pub formula: Option<fn(u: &Unit, v: f64) -> Result<f64, String>>,
+    pub factor: f64,
 }
 
-lazy_static! {
-    pub static ref UNITS: Vec<(&'static str, Vec<Unit>)> = vec![
+pub static SI_PREFIXES: [Prefix; 24] = [
+    Prefix {
+        symbols: &["Q"],
+        name: "quetta",
+        factor: 1e30,
+    },
+    Prefix {
+        symbols: &["R"],
+        name: "ronna",
+        factor: 1e27,
+    },
+    Prefix {
+        symbols: &["Y"],
+        name: "yotta",
+        factor: 1e24,
+    },
+    Prefix {
+        symbols: &["Z"],
+        name: "zetta",
+        factor: 1e21,
+    },
+    Prefix {
+        symbols: &["E"],
+        name: "exa",
+        factor: 1e18,
+    },
+    Prefix {
+        symbols: &["P"],
+        name: "peta",
+        factor: 1e15,
+    },
+    Prefix {
+        symbols: &["T"],
+        name: "tera",
+        factor: 1e12,
+    },
+    Prefix {
+        symbols: &["G"],
+        name: "giga",
+        factor: 1e9,
+    },
+    Prefix {
+        symbols: &["M"],
+        name: "mega",
+        factor: 1e6,
+    },
+    Prefix {
+        symbols: &["k"],
+        name: "kilo",
+        factor: 1e3,
+    },
+    Prefix {
+        symbols: &["h"],
+        name: "hecto",
+        factor: 1e2,
+    },
+    Prefix {
+        symbols: &["da"],
+        name: "deca",
+        factor: 1e1,
+    },
+    Prefix {
+        symbols: &["d"],
+        name: "deci",
+        factor: 1e-1,
+    },
+    Prefix {
+        symbols: &["c"],
+        name: "centi",
+        factor: 1e-2,
+    },
+    Prefix {
+        symbols: &["m"],
+        name: "milli",
+        factor: 1e-3,
+    },
+    Prefix {
+        symbols: &["μ", "µ", "u"],
+        name: "micro",
+        factor: 1e-6,
+    },
+    Prefix {
+        symbols: &["n"],
+        name: "nano",
+        factor: 1e-9,
+    },
+    Prefix {
+        symbols: &["p"],
+        name: "pico",
+        factor: 1e-12,
+    },
+    Prefix {
+        symbols: &["f"],
+        name: "femto",
+        factor: 1e-15,
+    },
+    Prefix {
+        symbols: &["a"],
+        name: "atto",
+        factor: 1e-18,
+    },
+    Prefix {
+        symbols: &["z"],
+        name: "zepto",
+        factor: 1e-21,
+    },
+    Prefix {
+        symbols: &["y"],
+        name: "yocto",
+        factor: 1e-24,
+    },
+    Prefix {
+        symbols: &["r"],
+        name: "ronto",
+        factor: 1e-27,
+    },
+    Prefix {
+        symbols: &["q"],
+        name: "quecto",
+        factor: 1e-30,
+    },
+];
+
+pub static IEC_PREFIXES: [Prefix; 8] = [
+    Prefix {
+        symbols: &["Ki"],
+        name: "kibi",
+        factor: 1024.0,
+    },
+    Prefix {
+        symbols: &["Mi"],
+        name: "mebi",
+        factor: 1048576.0,
+    },
+    Prefix {
+        symbols: &["Gi"],
+        name: "gibi",
+        factor: 1073741824.0,
+    },
+    Prefix {
+        symbols: &["Ti"],
+        name: "tebi",
+        factor: 1099511627776.0,
+    },
+    Prefix {
+        symbols: &["Pi"],
+        name: "pebi",
+        factor: 1125899906842624.0,
+    },
+    Prefix {
+        symbols: &["Ei"],
+        name: "exbi",
+        factor: 1152921504606846976.0,
+    },
+    Prefix {
+        symbols: &["Zi"],
+        name: "zebi",
+        factor: 1180591620717411303424.0,
+    },
+    Prefix {
+        symbols: &["Yi"],
+        name: "yobi",
+        factor: 1208925819614629174706176.0,
+    },
+];
+
+// the prefixes a unit accepts, along with the symbols of the unit they can be attached to
+// (the first symbol is used in the abbreviation of the prefixed unit)
+#[derive(Debug, Clone)]
+pub enum Prefixes {
+    None,
+    // all the SI prefixes (e.g. "km", "µs" or "GJ")
+    Si(Vec<String>),
+    // the SI prefixes from kilo upwards and the IEC prefixes, for digital storage (e.g. "kB" or "MiB")
+    Storage(Vec<String>),
+}
+
+impl Prefixes {
+    pub fn prefixes(&self) -> Vec<&'static Prefix> {
+        match self {
+            Prefixes::None => Vec::new(),
+            Prefixes::Si(_) => SI_PREFIXES.iter().collect(),
+            Prefixes::Storage(_) => SI_PREFIXES
+                .iter()
+                .filter(|p| p.factor >= 1e3)
+                .chain(IEC_PREFIXES.iter())
+                .collect(),
+        }
+    }
+
+    pub fn symbols(&self) -> &[String] {
+        match self {
+            Prefixes::None => &[],
+            Prefixes::Si(symbols) | Prefixes::Storage(symbols) => symbols,
+        }
+    }
+}
+
+fn strings(strs: &[&str]) -> Vec<String> {
+    strs.iter().map(|s| s.to_string()).collect()
+}
+
+fn ratios<T: Into<Definition> + Copy>(ratios: &[(&str, T)]) -> Vec<(String, Definition)> {
+    ratios
+        .iter()
+        .map(|(label, ratio)| (label.to_string(), (*ratio).into()))
+        .collect()
+}
+
+// the built-in unit types of differences (see UnitRegistry::add_difference_type)
+pub(crate) fn builtin_difference_types() -> Vec<(&'static str, &'static str)> {
+    vec![("TEMPERATURE", "TEMPERATURE DIFFERENCE")]
+}
+
+// the built-in unit types of products of two unit types (see UnitRegistry::add_product_type)
+pub(crate) fn builtin_product_types() -> Vec<(&'static str, &'static str, &'static str)> {
+    vec![("TORQUE", "FORCE", "LENGTH")]
+}
+
+// the built-in units table, per unit type (see UnitRegistry::default)
+pub(crate) fn builtin_units() -> Vec<(&'static str, Vec<Unit>)> {
+    vec![
         (
             "AREA",
             vec![
                 Unit {
-                    name: "Square Meter",
-                    abbr: "m²",
-                    aliases: &[
+                    name: "Square Meter".to_string(),
+                    abbr: "m²".to_string(),
+                    aliases: strings(&[
                         "m2",
                         "sq m",
                         "square meter",
                         "square meters",
                         "square metre",
-                        "square metres"
-                    ],
-                    ratios: Some(vec![("", 1.0)]),
-                    formula: None,
+                        "square metres",
+                    ]),
+                    dimension: Dimension::AREA,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
                 },
                 Unit {
-                    name: "Square Kilometer",
-                    abbr: "km²",
-                    aliases: &[
+                    name: "Square Kilometer".to_string(),
+                    abbr: "km²".to_string(),
+                    aliases: strings(&[
                         "km2",
                         "sq km",
                         "square kilometer",
                         "square kilometers",
                         "square kilometre",
-                        "square kilometres"
-                    ],
-                    ratios: Some(vec![("", 1e+6)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Square Mile",
-                    abbr: "mi²",
-                    aliases: &["mi2", "sq mi", "square mile", "square miles"],
-                    ratios: Some(vec![("", 2.59e+6)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Square Yard",
-                    abbr: "yd²",
-                    aliases: &["yd2", "sq yd", "square yard", "square yards"],
-                    ratios: Some(vec![("", 0.836127)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Square Foot",
-                    abbr: "ft²",
-                    aliases: &["ft2", "sq ft", "square foot", "square feet"],
-                    ratios: Some(vec![("", 0.092903)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Square Inch",
-                    abbr: "in²",
-                    aliases: &["in2", "sq in", "square inch", "square inches"],
-                    ratios: Some(vec![("", 0.00064516)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Hectare",
-                    abbr: "ha",
-                    aliases: &["hectare", "hectares"],
-                    ratios: Some(vec![("", 10000.0)]),
-                    formula: None,
-                },
+                        "square kilometres",
+                    ]),
+                    dimension: Dimension::AREA,
+                    ratios: ratios(&[("", 1e+6)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Square Mile".to_string(),
+                    abbr: "mi²".to_string(),
+                    aliases: strings(&["mi2", "sq mi", "square mile", "square miles"]),
+                    dimension: Dimension::AREA,
+                    ratios: ratios(&[("", MILE * MILE)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Square Yard".to_string(),
+                    abbr: "yd²".to_string(),
+                    aliases: strings(&["yd2", "sq yd", "square yard", "square yards"]),
+                    dimension: Dimension::AREA,
+                    ratios: ratios(&[("", YARD * YARD)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Square Foot".to_string(),
+                    abbr: "ft²".to_string(),
+                    aliases: strings(&["ft2", "sq ft", "square foot", "square feet"]),
+                    dimension: Dimension::AREA,
+                    ratios: ratios(&[("", FOOT * FOOT)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Square Inch".to_string(),
+                    abbr: "in²".to_string(),
+                    aliases: strings(&["in2", "sq in", "square inch", "square inches"]),
+                    dimension: Dimension::AREA,
+                    ratios: ratios(&[("", INCH * INCH)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Hectare".to_string(),
+                    abbr: "ha".to_string(),
+                    aliases: strings(&["hectare", "hectares"]),
+                    dimension: Dimension::AREA,
+                    ratios: ratios(&[("", 10000.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Acre".to_string(),
+                    abbr: "a".to_string(),
+                    aliases: strings(&["acre", "acres"]),
+                    dimension: Dimension::AREA,
+                    ratios: ratios(&[("", 43_560.0 * FOOT * FOOT)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+            ],
+        ),
+        (
+            "DATA RATE",
+            vec![
                 Unit {
-                    name: "Acre",
-                    abbr: "a",
-                    aliases: &["acre", "acres"],
-                    ratios: Some(vec![("", 4046.86)]),
-                    formula: None,
-                },
-            ]
+                    name: "Bit per Second".to_string(),
+                    abbr: "bps".to_string(),
+                    aliases: strings(&["bit/s", "bit per second", "bits per second"]),
+                    dimension: Dimension::DATA_RATE,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Storage(strings(&["bps", "bit/s", "b/s"])),
+                },
+                Unit {
+                    name: "Kilobit per Second".to_string(),
+                    abbr: "kbps".to_string(),
+                    aliases: strings(&["kbit/s", "kilobit per second", "kilobits per second"]),
+                    dimension: Dimension::DATA_RATE,
+                    ratios: ratios(&[("", 1000.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Megabit per Second".to_string(),
+                    abbr: "Mbps".to_string(),
+                    aliases: strings(&["mbit/s", "megabit per second", "megabits per second"]),
+                    dimension: Dimension::DATA_RATE,
+                    ratios: ratios(&[("", 1e+6)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Gigabit per Second".to_string(),
+                    abbr: "Gbps".to_string(),
+                    aliases: strings(&["gbit/s", "gigabit per second", "gigabits per second"]),
+                    dimension: Dimension::DATA_RATE,
+                    ratios: ratios(&[("", 1e+9)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Byte per Second".to_string(),
+                    abbr: "B/s".to_string(),
+                    aliases: strings(&["byte per second", "bytes per second"]),
+                    dimension: Dimension::DATA_RATE,
+                    ratios: ratios(&[("", 8.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Storage(strings(&["B/s", "Bps"])),
+                },
+                Unit {
+                    name: "Kilobyte per Second".to_string(),
+                    abbr: "kB/s".to_string(),
+                    aliases: strings(&["KB/s", "kilobyte per second", "kilobytes per second"]),
+                    dimension: Dimension::DATA_RATE,
+                    ratios: ratios(&[("", 8000.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Megabyte per Second".to_string(),
+                    abbr: "MB/s".to_string(),
+                    aliases: strings(&["megabyte per second", "megabytes per second"]),
+                    dimension: Dimension::DATA_RATE,
+                    ratios: ratios(&[("", 8e+6)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Mebibyte per Second".to_string(),
+                    abbr: "MiB/s".to_string(),
+                    aliases: strings(&["MiBps", "mebibyte per second", "mebibytes per second"]),
+                    dimension: Dimension::DATA_RATE,
+                    ratios: ratios(&[("", 8388608.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Gigabyte per Second".to_string(),
+                    abbr: "GB/s".to_string(),
+                    aliases: strings(&["gigabyte per second", "gigabytes per second"]),
+                    dimension: Dimension::DATA_RATE,
+                    ratios: ratios(&[("", 8e+9)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Gibibyte per Second".to_string(),
+                    abbr: "GiB/s".to_string(),
+                    aliases: strings(&["GiBps", "gibibyte per second", "gibibytes per second"]),
+                    dimension: Dimension::DATA_RATE,
+                    ratios: ratios(&[("", 8589934592.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+            ],
         ),
         (
             "DIGITAL STORAGE",
             vec![
                 Unit {
-                    name: "Bit",
-                    abbr: "b",
-                    aliases: &["bit", "bits"],
-                    ratios: Some(vec![("", 1.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Kilobit",
-                    abbr: "kb",
-                    aliases: &["kbit", "kbits", "kilobit", "kilobits"],
-                    ratios: Some(vec![("", 1000.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Kibibit",
-                    abbr: "Kib",
-                    aliases: &["kibit", "kibits", "kibibit", "kibibits"],
-                    ratios: Some(vec![("", 1024.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Megabit",
-                    abbr: "Mb",
-                    aliases: &["mbit", "mbits", "megabit", "megabits"],
-                    ratios: Some(vec![("", 1e+6)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Mebibit",
-                    abbr: "Mib",
-                    aliases: &["mibit", "mibits", "mebibit", "mebibits"],
-                    ratios: Some(vec![("", 1048576.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Gigabit",
-                    abbr: "Gb",
-                    aliases: &["gbit", "gbits", "gigabit", "gigabits"],
-                    ratios: Some(vec![("", 1e+9)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Gibibit",
-                    abbr: "Gib",
-                    aliases: &["gibit", "gibits", "gibibit", "gibibits"],
-                    ratios: Some(vec![("", 1073741824.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Terabit",
-                    abbr: "Tb",
-                    aliases: &["tbit", "tbits", "terabit", "terabits"],
-                    ratios: Some(vec![("", 1e+12)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Tebibit",
-                    abbr: "Tib",
-                    aliases: &["tibit", "tibits", "tebibit", "tebibits"],
-                    ratios: Some(vec![("", 1099511627776.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Petabit",
-                    abbr: "Pb",
-                    aliases: &["pbit", "pbits", "petabit", "petabits"],
-                    ratios: Some(vec![("", 1e+15)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Pebibit",
-                    abbr: "Pib",
-                    aliases: &["pibit", "pibits", "pebibit", "pebibits"],
-                    ratios: Some(vec![("", 1125899906842624.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Byte",
-                    abbr: "B",
-                    aliases: &["byte", "bytes"],
-                    ratios: Some(vec![("", 8.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Kilobyte",
-                    abbr: "kB",
-                    aliases: &["kbyte", "kbytes", "kilobyte", "kilobytes"],
-                    ratios: Some(vec![("", 8000.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Kibibyte",
-                    abbr: "KiB",
-                    aliases: &["kibyte", "kibytes", "kibibyte", "kibibytes"],
-                    ratios: Some(vec![("", 8192.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Megabyte",
-                    abbr: "MB",
-                    aliases: &["mbyte", "mbytes", "megabyte", "megabytes"],
-                    ratios: Some(vec![("", 8e+6)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Mebibyte",
-                    abbr: "MiB",
-                    aliases: &["mibyte", "mibytes", "mebibyte", "mebibytes"],
-                    ratios: Some(vec![("", 8388608.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Gigabyte",
-                    abbr: "GB",
-                    aliases: &["gbyte", "gbytes", "gigabyte", "gigabytes"],
-                    ratios: Some(vec![("", 8e+9)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Gibibyte",
-                    abbr: "GiB",
-                    aliases: &["gibyte", "gibytes", "gibibyte", "gibibytes"],
-                    ratios: Some(vec![("", 8589934592.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Terabyte",
-                    abbr: "TB",
-                    aliases: &["tbyte", "tbytes", "terabyte", "terabytes"],
-                    ratios: Some(vec![("", 8e+12)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Tebibyte",
-                    abbr: "TiB",
-                    aliases: &["tibyte", "tibytes", "tebibyte", "tebibytes"],
-                    ratios: Some(vec![("", 8796093022208.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Petabyte",
-                    abbr: "PB",
-                    aliases: &["pbyte", "pbytes", "petabyte", "petabytes"],
-                    ratios: Some(vec![("", 8e+15)]),
-                    formula: None,
-                },
+                    name: "Bit".to_string(),
+                    abbr: "b".to_string(),
+                    aliases: strings(&["bit", "bits"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Storage(strings(&["b"])),
+                },
+                Unit {
+                    name: "Kilobit".to_string(),
+                    abbr: "kb".to_string(),
+                    aliases: strings(&["kbit", "kbits", "kilobit", "kilobits"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 1000.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Kibibit".to_string(),
+                    abbr: "Kib".to_string(),
+                    aliases: strings(&["kibit", "kibits", "kibibit", "kibibits"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 1024.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Megabit".to_string(),
+                    abbr: "Mb".to_string(),
+                    aliases: strings(&["mbit", "mbits", "megabit", "megabits"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 1e+6)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Mebibit".to_string(),
+                    abbr: "Mib".to_string(),
+                    aliases: strings(&["mibit", "mibits", "mebibit", "mebibits"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 1048576.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Gigabit".to_string(),
+                    abbr: "Gb".to_string(),
+                    aliases: strings(&["gbit", "gbits", "gigabit", "gigabits"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 1e+9)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Gibibit".to_string(),
+                    abbr: "Gib".to_string(),
+                    aliases: strings(&["gibit", "gibits", "gibibit", "gibibits"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 1073741824.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Terabit".to_string(),
+                    abbr: "Tb".to_string(),
+                    aliases: strings(&["tbit", "tbits", "terabit", "terabits"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 1e+12)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Tebibit".to_string(),
+                    abbr: "Tib".to_string(),
+                    aliases: strings(&["tibit", "tibits", "tebibit", "tebibits"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 1099511627776.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Petabit".to_string(),
+                    abbr: "Pb".to_string(),
+                    aliases: strings(&["pbit", "pbits", "petabit", "petabits"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 1e+15)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Pebibit".to_string(),
+                    abbr: "Pib".to_string(),
+                    aliases: strings(&["pibit", "pibits", "pebibit", "pebibits"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 1125899906842624.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Byte".to_string(),
+                    abbr: "B".to_string(),
+                    aliases: strings(&["byte", "bytes"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 8.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Storage(strings(&["B"])),
+                },
+                Unit {
+                    name: "Kilobyte".to_string(),
+                    abbr: "kB".to_string(),
+                    aliases: strings(&["kbyte", "kbytes", "kilobyte", "kilobytes"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 8000.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Kibibyte".to_string(),
+                    abbr: "KiB".to_string(),
+                    aliases: strings(&["kibyte", "kibytes", "kibibyte", "kibibytes"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 8192.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Megabyte".to_string(),
+                    abbr: "MB".to_string(),
+                    aliases: strings(&["mbyte", "mbytes", "megabyte", "megabytes"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 8e+6)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Mebibyte".to_string(),
+                    abbr: "MiB".to_string(),
+                    aliases: strings(&["mibyte", "mibytes", "mebibyte", "mebibytes"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 8388608.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Gigabyte".to_string(),
+                    abbr: "GB".to_string(),
+                    aliases: strings(&["gbyte", "gbytes", "gigabyte", "gigabytes"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 8e+9)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Gibibyte".to_string(),
+                    abbr: "GiB".to_string(),
+                    aliases: strings(&["gibyte", "gibytes", "gibibyte", "gibibytes"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 8589934592.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Terabyte".to_string(),
+                    abbr: "TB".to_string(),
+                    aliases: strings(&["tbyte", "tbytes", "terabyte", "terabytes"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 8e+12)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Tebibyte".to_string(),
+                    abbr: "TiB".to_string(),
+                    aliases: strings(&["tibyte", "tibytes", "tebibyte", "tebibytes"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 8796093022208.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Petabyte".to_string(),
+                    abbr: "PB".to_string(),
+                    aliases: strings(&["pbyte", "pbytes", "petabyte", "petabytes"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 8e+15)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Pebibyte".to_string(),
+                    abbr: "PiB".to_string(),
+                    aliases: strings(&["pibyte", "pibytes", "pebibyte", "pebibytes"]),
+                    dimension: Dimension::INFORMATION,
+                    ratios: ratios(&[("", 9007199254740992.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+            ],
+        ),
+        (
+            "ELECTRIC CAPACITANCE",
+            vec![Unit {
+                name: "Farad".to_string(),
+                abbr: "F".to_string(),
+                aliases: strings(&["farad", "farads"]),
+                dimension: Dimension::CAPACITANCE,
+                ratios: ratios(&[("", 1.0)]),
+                offset: Definition::ZERO,
+                scale: Scale::Linear,
+                prefixes: Prefixes::Si(strings(&["F"])),
+            }],
+        ),
+        (
+            "ELECTRIC CHARGE",
+            vec![
                 Unit {
-                    name: "Pebibyte",
-                    abbr: "PiB",
-                    aliases: &["pibyte", "pibytes", "pebibyte", "pebibytes"],
-                    ratios: Some(vec![("", 9007199254740992.0)]),
-                    formula: None,
+                    name: "Coulomb".to_string(),
+                    abbr: "C".to_string(),
+                    aliases: strings(&["coulomb", "coulombs"]),
+                    dimension: Dimension::CHARGE,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["C"])),
+                },
+                // the battery capacity unit (e.g. "mAh")
+                Unit {
+                    name: "Ampere-Hour".to_string(),
+                    abbr: "Ah".to_string(),
+                    aliases: strings(&[
+                        "A⋅h",
+                        "A*h",
+                        "A·h",
+                        "ampere-hour",
+                        "ampere-hours",
+                        "amp-hour",
+                        "amp-hours",
+                    ]),
+                    dimension: Dimension::CHARGE,
+                    ratios: ratios(&[("", 3600.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["Ah"])),
                 },
-            ]
+            ],
+        ),
+        (
+            "ELECTRIC CONDUCTANCE",
+            vec![Unit {
+                name: "Siemens".to_string(),
+                abbr: "S".to_string(),
+                aliases: strings(&["siemens", "mho", "mhos"]),
+                dimension: Dimension::CONDUCTANCE,
+                ratios: ratios(&[("", 1.0)]),
+                offset: Definition::ZERO,
+                scale: Scale::Linear,
+                prefixes: Prefixes::Si(strings(&["S"])),
+            }],
+        ),
+        (
+            "ELECTRIC CURRENT",
+            vec![Unit {
+                name: "Ampere".to_string(),
+                abbr: "A".to_string(),
+                aliases: strings(&["ampere", "amperes", "amp", "amps"]),
+                dimension: Dimension::CURRENT,
+                ratios: ratios(&[("", 1.0)]),
+                offset: Definition::ZERO,
+                scale: Scale::Linear,
+                prefixes: Prefixes::Si(strings(&["A"])),
+            }],
+        ),
+        (
+            "ELECTRIC RESISTANCE",
+            vec![Unit {
+                name: "Ohm".to_string(),
+                abbr: "Ω".to_string(),
+                aliases: strings(&["ohm", "ohms"]),
+                dimension: Dimension::RESISTANCE,
+                ratios: ratios(&[("", 1.0)]),
+                offset: Definition::ZERO,
+                scale: Scale::Linear,
+                prefixes: Prefixes::Si(strings(&["Ω", "ohm"])),
+            }],
         ),
         (
             "ENERGY",
             vec![
                 Unit {
-                    name: "Joule",
-                    abbr: "J",
-                    aliases: &["joule", "joules"],
-                    ratios: Some(vec![("", 1.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Kilojoule",
-                    abbr: "kJ",
-                    aliases: &["kilojoule", "kilojoules"],
-                    ratios: Some(vec![("", 1000.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Calorie",
-                    abbr: "cal",
-                    aliases: &["cals", "calorie", "calories"],
-                    ratios: Some(vec![("", 4.184)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Kilocalorie",
-                    abbr: "kcal",
-                    aliases: &["kcals", "kilocalorie", "kilocalories"],
-                    ratios: Some(vec![("", 4184.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Watt-hour",
-                    abbr: "W⋅h",
-                    aliases: &[
+                    name: "Joule".to_string(),
+                    abbr: "J".to_string(),
+                    aliases: strings(&["joule", "joules"]),
+                    dimension: Dimension::ENERGY,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["J"])),
+                },
+                Unit {
+                    name: "Kilojoule".to_string(),
+                    abbr: "kJ".to_string(),
+                    aliases: strings(&["kilojoule", "kilojoules"]),
+                    dimension: Dimension::ENERGY,
+                    ratios: ratios(&[("", 1000.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Calorie".to_string(),
+                    abbr: "cal".to_string(),
+                    aliases: strings(&["cals", "calorie", "calories"]),
+                    dimension: Dimension::ENERGY,
+                    ratios: ratios(&[("", CALORIE)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Kilocalorie".to_string(),
+                    abbr: "kcal".to_string(),
+                    aliases: strings(&["kcals", "kilocalorie", "kilocalories"]),
+                    dimension: Dimension::ENERGY,
+                    ratios: ratios(&[("", 1000.0 * CALORIE)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Watt-hour".to_string(),
+                    abbr: "W⋅h".to_string(),
+                    aliases: strings(&[
                         "wh",
                         "whs",
                         "watt-hour",
                         "watt-hours",
                         "watt hour",
-                        "watt hours"
-                    ],
-                    ratios: Some(vec![("", 3600.0)]),
-                    formula: None,
+                        "watt hours",
+                    ]),
+                    dimension: Dimension::ENERGY,
+                    ratios: ratios(&[("", 3600.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["W⋅h", "Wh"])),
                 },
                 Unit {
-                    name: "Kilowatt-hour",
-                    abbr: "kW⋅h",
-                    aliases: &[
+                    name: "Kilowatt-hour".to_string(),
+                    abbr: "kW⋅h".to_string(),
+                    aliases: strings(&[
                         "kwh",
                         "kwhs",
                         "kilowatt-hour",
                         "kilowatt-hours",
                         "kilowatt hour",
-                        "kilowatt hours"
-                    ],
-                    ratios: Some(vec![("", 3600000.0)]),
-                    formula: None,
+                        "kilowatt hours",
+                    ]),
+                    dimension: Dimension::ENERGY,
+                    ratios: ratios(&[("", 3600000.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
                 },
                 Unit {
-                    name: "Electronvolt",
-                    abbr: "eV",
-                    aliases: &[
+                    name: "Electronvolt".to_string(),
+                    abbr: "eV".to_string(),
+                    aliases: strings(&[
                         "evs",
                         "electronvolt",
                         "electronvolts",
                         "electron-volt",
                         "electron-volts",
                         "electron volt",
-                        "electron volts"
-                    ],
-                    ratios: Some(vec![("", 1.6022e-19)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "British Thermal Unit",
-                    abbr: "Btu",
-                    aliases: &["btus", "british thermal unit", "british thermal units"],
-                    ratios: Some(vec![("", 1055.06)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Therm",
-                    abbr: "thm",
-                    aliases: &["thms", "therm", "therms"],
-                    ratios: Some(vec![("", 1.055e+8)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Foot-Pound Force",
-                    abbr: "ft⋅lbf",
-                    aliases: &[
+                        "electron volts",
+                    ]),
+                    dimension: Dimension::ENERGY,
+                    ratios: ratios(&[("", ELEMENTARY_CHARGE)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["eV"])),
+                },
+                Unit {
+                    name: "British Thermal Unit".to_string(),
+                    abbr: "Btu".to_string(),
+                    aliases: strings(&["btus", "british thermal unit", "british thermal units"]),
+                    dimension: Dimension::ENERGY,
+                    ratios: ratios(&[("", BTU)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Therm".to_string(),
+                    abbr: "thm".to_string(),
+                    aliases: strings(&["thms", "therm", "therms"]),
+                    dimension: Dimension::ENERGY,
+                    ratios: ratios(&[("", 100_000.0 * BTU)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Foot-Pound Force".to_string(),
+                    abbr: "ft⋅lbf".to_string(),
+                    aliases: strings(&[
                         "ftlbf",
                         "ftlbfs",
                         "ftlb",
@@ -344,239 +929,697 @@ lazy_static! {
                         "foot-pound force",
                         "foot pound force",
                         "foot-pound",
-                        "foot pound"
-                    ],
-                    ratios: Some(vec![("", 1.35582)]),
-                    formula: None,
+                        "foot pound",
+                    ]),
+                    dimension: Dimension::ENERGY,
+                    ratios: ratios(&[("", FOOT * POUND_FORCE)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
                 },
-            ]
+            ],
         ),
         (
-            "FREQUENCY",
+            "FORCE",
             vec![
                 Unit {
-                    name: "Hertz",
-                    abbr: "Hz",
-                    aliases: &["hertz"],
-                    ratios: Some(vec![("", 1.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Kilohertz",
-                    abbr: "kHz",
-                    aliases: &["kilohertz"],
-                    ratios: Some(vec![("", 1000.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Megahertz",
-                    abbr: "MHz",
-                    aliases: &["megahertz"],
-                    ratios: Some(vec![("", 1e+6)]),
-                    formula: None,
-                },
+                    name: "Newton".to_string(),
+                    abbr: "N".to_string(),
+                    aliases: strings(&["newton", "newtons"]),
+                    dimension: Dimension::FORCE,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["N"])),
+                },
+                Unit {
+                    name: "Kilogram-Force".to_string(),
+                    abbr: "kgf".to_string(),
+                    aliases: strings(&[
+                        "kilogram-force",
+                        "kilograms-force",
+                        "kilopond",
+                        "kiloponds",
+                        "kp",
+                    ]),
+                    dimension: Dimension::FORCE,
+                    ratios: ratios(&[("", STANDARD_GRAVITY)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Pound-Force".to_string(),
+                    abbr: "lbf".to_string(),
+                    aliases: strings(&["pound-force", "pounds-force"]),
+                    dimension: Dimension::FORCE,
+                    ratios: ratios(&[("", POUND_FORCE)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Kip".to_string(),
+                    abbr: "kip".to_string(),
+                    aliases: strings(&["kips", "kilopound-force", "kilopounds-force"]),
+                    dimension: Dimension::FORCE,
+                    ratios: ratios(&[("", 1000.0 * POUND_FORCE)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Poundal".to_string(),
+                    abbr: "pdl".to_string(),
+                    aliases: strings(&["poundal", "poundals"]),
+                    dimension: Dimension::FORCE,
+                    ratios: ratios(&[("", POUND * FOOT)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Dyne".to_string(),
+                    abbr: "dyn".to_string(),
+                    aliases: strings(&["dyne", "dynes"]),
+                    dimension: Dimension::FORCE,
+                    ratios: ratios(&[("", 1e-5)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+            ],
+        ),
+        (
+            "FREQUENCY",
+            vec![
                 Unit {
-                    name: "Gigahertz",
-                    abbr: "GHz",
-                    aliases: &["gigahertz"],
-                    ratios: Some(vec![("", 1e+9)]),
-                    formula: None,
+                    name: "Hertz".to_string(),
+                    abbr: "Hz".to_string(),
+                    aliases: strings(&["hertz"]),
+                    dimension: Dimension::FREQUENCY,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["Hz"])),
+                },
+                Unit {
+                    name: "Kilohertz".to_string(),
+                    abbr: "kHz".to_string(),
+                    aliases: strings(&["kilohertz"]),
+                    dimension: Dimension::FREQUENCY,
+                    ratios: ratios(&[("", 1000.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Megahertz".to_string(),
+                    abbr: "MHz".to_string(),
+                    aliases: strings(&["megahertz"]),
+                    dimension: Dimension::FREQUENCY,
+                    ratios: ratios(&[("", 1e+6)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Gigahertz".to_string(),
+                    abbr: "GHz".to_string(),
+                    aliases: strings(&["gigahertz"]),
+                    dimension: Dimension::FREQUENCY,
+                    ratios: ratios(&[("", 1e+9)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+            ],
+        ),
+        (
+            "FUEL ECONOMY",
+            vec![
+                // (the distance per volume, in m/m³)
+                Unit {
+                    name: "Kilometer per Liter".to_string(),
+                    abbr: "km/L".to_string(),
+                    aliases: strings(&[
+                        "km/l",
+                        "kmpl",
+                        "kilometer per liter",
+                        "kilometers per liter",
+                        "kilometre per litre",
+                        "kilometres per litre",
+                    ]),
+                    dimension: Dimension::FUEL_ECONOMY,
+                    ratios: ratios(&[("", 1e+6)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Mile per Gallon".to_string(),
+                    abbr: "mpg".to_string(),
+                    aliases: strings(&["mi/gal", "mile per gallon", "miles per gallon"]),
+                    dimension: Dimension::FUEL_ECONOMY,
+                    ratios: ratios(&[
+                        ("Imperial", MILE / IMPERIAL_GALLON),
+                        ("US", MILE / US_GALLON),
+                    ]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Mile per Liter".to_string(),
+                    abbr: "mi/L".to_string(),
+                    aliases: strings(&[
+                        "mi/l",
+                        "mile per liter",
+                        "miles per liter",
+                        "mile per litre",
+                        "miles per litre",
+                    ]),
+                    dimension: Dimension::FUEL_ECONOMY,
+                    ratios: ratios(&[("", MILE / 1e-3)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                // the fuel consumption: the volume per distance (an inverse unit)
+                Unit {
+                    name: "Liter per 100 Kilometers".to_string(),
+                    abbr: "L/100km".to_string(),
+                    aliases: strings(&[
+                        "l/100km",
+                        "L/100 km",
+                        "l/100 km",
+                        "liters per 100 kilometers",
+                        "litres per 100 kilometres",
+                    ]),
+                    dimension: Dimension::FUEL_ECONOMY,
+                    ratios: ratios(&[("", 1e+8)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Inverse,
+                    prefixes: Prefixes::None,
                 },
-            ]
+            ],
+        ),
+        (
+            "INDUCTANCE",
+            vec![Unit {
+                name: "Henry".to_string(),
+                abbr: "H".to_string(),
+                aliases: strings(&["henry", "henries", "henrys"]),
+                dimension: Dimension::INDUCTANCE,
+                ratios: ratios(&[("", 1.0)]),
+                offset: Definition::ZERO,
+                scale: Scale::Linear,
+                prefixes: Prefixes::Si(strings(&["H"])),
+            }],
         ),
         (
             "LENGTH",
             vec![
                 Unit {
-                    name: "Meter",
-                    abbr: "m",
-                    aliases: &["meter", "meters", "metre", "metres"],
-                    ratios: Some(vec![("", 1.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Kilometer",
-                    abbr: "km",
-                    aliases: &["kilometer", "kilometers", "kilometre", "kilometres"],
-                    ratios: Some(vec![("", 1000.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Centimeter",
-                    abbr: "cm",
-                    aliases: &["centimeter", "centimeters", "centimetre", "centimetres"],
-                    ratios: Some(vec![("", 0.01)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Millimeter",
-                    abbr: "mm",
-                    aliases: &["millimeter", "millimeters", "millimetre", "millimetres"],
-                    ratios: Some(vec![("", 0.001)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Micrometer",
-                    abbr: "μm",
-                    aliases: &["micrometer", "micrometers", "micrometre", "micrometres"],
-                    ratios: Some(vec![("", 1e-6)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Nanometer",
-                    abbr: "nm",
-                    aliases: &["nanometer", "nanometers", "nanometre", "nanometres"],
-                    ratios: Some(vec![("", 1e-9)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Mile",
-                    abbr: "mi",
-                    aliases: &["mile", "miles"],
-                    ratios: Some(vec![("", 1609.34)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Nautical Mile",
-                    abbr: "nmi",
-                    aliases: &["nautical mile", "nautical miles"],
-                    ratios: Some(vec![("", 1852.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Yard",
-                    abbr: "yd",
-                    aliases: &["yard", "yards"],
-                    ratios: Some(vec![("", 0.9144)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Foot",
-                    abbr: "ft",
-                    aliases: &["foot", "feet"],
-                    ratios: Some(vec![("", 0.3048)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Inch",
-                    abbr: "in",
-                    aliases: &["in", "inch", "inches"],
-                    ratios: Some(vec![("", 0.0254)]),
-                    formula: None,
-                },
-            ]
+                    name: "Meter".to_string(),
+                    abbr: "m".to_string(),
+                    aliases: strings(&["meter", "meters", "metre", "metres"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["m"])),
+                },
+                Unit {
+                    name: "Kilometer".to_string(),
+                    abbr: "km".to_string(),
+                    aliases: strings(&["kilometer", "kilometers", "kilometre", "kilometres"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", 1000.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Centimeter".to_string(),
+                    abbr: "cm".to_string(),
+                    aliases: strings(&["centimeter", "centimeters", "centimetre", "centimetres"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", 0.01)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Millimeter".to_string(),
+                    abbr: "mm".to_string(),
+                    aliases: strings(&["millimeter", "millimeters", "millimetre", "millimetres"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", 0.001)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Micrometer".to_string(),
+                    abbr: "μm".to_string(),
+                    aliases: strings(&["micrometer", "micrometers", "micrometre", "micrometres"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", 1e-6)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Nanometer".to_string(),
+                    abbr: "nm".to_string(),
+                    aliases: strings(&["nanometer", "nanometers", "nanometre", "nanometres"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", 1e-9)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Picometer".to_string(),
+                    abbr: "pm".to_string(),
+                    aliases: strings(&["picometer", "picometers", "picometre", "picometres"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", 1e-12)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Femtometer".to_string(),
+                    abbr: "fm".to_string(),
+                    aliases: strings(&[
+                        "fermi",
+                        "fermis",
+                        "femtometer",
+                        "femtometers",
+                        "femtometre",
+                        "femtometres",
+                    ]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", 1e-15)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Ångström".to_string(),
+                    abbr: "Å".to_string(),
+                    aliases: strings(&["angstrom", "angstroms", "ångström", "ångströms"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", 1e-10)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Planck Length".to_string(),
+                    abbr: "lP".to_string(),
+                    aliases: strings(&["ℓP", "planck length", "planck lengths"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", PLANCK_LENGTH)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Mile".to_string(),
+                    abbr: "mi".to_string(),
+                    aliases: strings(&["mile", "miles"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", MILE)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Nautical Mile".to_string(),
+                    abbr: "nmi".to_string(),
+                    aliases: strings(&["nautical mile", "nautical miles"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", NAUTICAL_MILE)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Yard".to_string(),
+                    abbr: "yd".to_string(),
+                    aliases: strings(&["yard", "yards"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", YARD)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Foot".to_string(),
+                    abbr: "ft".to_string(),
+                    aliases: strings(&["foot", "feet"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", FOOT)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Inch".to_string(),
+                    abbr: "in".to_string(),
+                    aliases: strings(&["in", "inch", "inches"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", INCH)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                // the US survey foot is a unit of its own rather than a ratio variant of the foot (as for the
+                // surveying units below): the variants differ by 2 ppm, so every conversion of feet would
+                // list two results that read the same at the default precision
+                Unit {
+                    name: "US Survey Foot".to_string(),
+                    abbr: "ftUS".to_string(),
+                    aliases: strings(&[
+                        "survey foot",
+                        "survey feet",
+                        "us survey foot",
+                        "us survey feet",
+                    ]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", US_SURVEY_FOOT)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Mil".to_string(),
+                    abbr: "mil".to_string(),
+                    aliases: strings(&["mils", "thou", "thous"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", INCH / 1000.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Fathom".to_string(),
+                    abbr: "ftm".to_string(),
+                    aliases: strings(&["fathom", "fathoms"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", 6.0 * FOOT)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                // the surveying units are defined in (international or US survey) feet:
+                // a rod is 16.5 ft, a chain 66 ft, a furlong 660 ft and a league 3 miles (15840 ft)
+                Unit {
+                    name: "Rod".to_string(),
+                    abbr: "rd".to_string(),
+                    aliases: strings(&["rod", "rods", "perch", "perches", "pole", "poles"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[
+                        ("International", 16.5 * FOOT),
+                        ("US survey", 16.5 * US_SURVEY_FOOT),
+                    ]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Chain".to_string(),
+                    abbr: "ch".to_string(),
+                    aliases: strings(&["chain", "chains"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[
+                        ("International", 66.0 * FOOT),
+                        ("US survey", 66.0 * US_SURVEY_FOOT),
+                    ]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Furlong".to_string(),
+                    abbr: "fur".to_string(),
+                    aliases: strings(&["furlong", "furlongs"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[
+                        ("International", 660.0 * FOOT),
+                        ("US survey", 660.0 * US_SURVEY_FOOT),
+                    ]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "League".to_string(),
+                    abbr: "lea".to_string(),
+                    aliases: strings(&["league", "leagues"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[
+                        ("International", 15_840.0 * FOOT),
+                        ("US survey", 15_840.0 * US_SURVEY_FOOT),
+                    ]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                // a tenth of a nautical mile, 608 ft in the Royal Navy and 120 fathoms (720 ft) in the US
+                Unit {
+                    name: "Cable".to_string(),
+                    abbr: "cbl".to_string(),
+                    aliases: strings(&["cable", "cables", "cable length", "cable lengths"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[
+                        ("International", NAUTICAL_MILE / 10.0),
+                        ("Imperial", 608.0 * FOOT),
+                        ("US", 720.0 * FOOT),
+                    ]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                // the desktop publishing (PostScript) point
+                Unit {
+                    name: "Point".to_string(),
+                    abbr: "pt".to_string(),
+                    aliases: strings(&["point", "points"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", INCH / 72.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Pica".to_string(),
+                    abbr: "pica".to_string(),
+                    aliases: strings(&["picas"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", INCH / 6.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Astronomical Unit".to_string(),
+                    abbr: "au".to_string(),
+                    aliases: strings(&["AU", "astronomical unit", "astronomical units"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", ASTRONOMICAL_UNIT)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Light-Second".to_string(),
+                    abbr: "ls".to_string(),
+                    aliases: strings(&[
+                        "light-second",
+                        "light-seconds",
+                        "light second",
+                        "light seconds",
+                    ]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", SPEED_OF_LIGHT)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                // the distance light travels in a Julian year
+                Unit {
+                    name: "Light-Year".to_string(),
+                    abbr: "ly".to_string(),
+                    aliases: strings(&["light-year", "light-years", "light year", "light years"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", SPEED_OF_LIGHT * JULIAN_YEAR)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["ly"])),
+                },
+                Unit {
+                    name: "Parsec".to_string(),
+                    abbr: "pc".to_string(),
+                    aliases: strings(&["parsec", "parsecs"]),
+                    dimension: Dimension::LENGTH,
+                    ratios: ratios(&[("", PARSEC)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["pc"])),
+                },
+            ],
         ),
         (
             "MASS",
             vec![
                 Unit {
-                    name: "Kilogram",
-                    abbr: "kg",
-                    aliases: &["kilogram", "kilograms"],
-                    ratios: Some(vec![("", 1.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Tonne • Metric / Imperial / US",
-                    abbr: "t",
-                    aliases: &["tonne", "tonnes"],
-                    ratios: Some(vec![
-                        ("Metric", 1000.0),
-                        ("Imperial", 1016.05),
-                        ("US", 907.185)
+                    name: "Kilogram".to_string(),
+                    abbr: "kg".to_string(),
+                    aliases: strings(&["kilogram", "kilograms"]),
+                    dimension: Dimension::MASS,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Tonne • Metric / Imperial / US".to_string(),
+                    abbr: "t".to_string(),
+                    aliases: strings(&["tonne", "tonnes"]),
+                    dimension: Dimension::MASS,
+                    ratios: ratios(&[
+                        ("Metric", Definition::from(1000.0)),
+                        ("Imperial", 2240.0 * POUND),
+                        ("US", 2000.0 * POUND),
                     ]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Gram",
-                    abbr: "gm",
-                    aliases: &["gram", "grams"],
-                    ratios: Some(vec![("", 0.001)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Milligram",
-                    abbr: "mg",
-                    aliases: &["milligram", "milligrams"],
-                    ratios: Some(vec![("", 1e-6)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Microgram",
-                    abbr: "µg",
-                    aliases: &["microgram", "micrograms"],
-                    ratios: Some(vec![("", 1e-9)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Stone",
-                    abbr: "st",
-                    aliases: &["stone", "stones"],
-                    ratios: Some(vec![("", 6.35029)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Pound",
-                    abbr: "lb",
-                    aliases: &["pound", "pounds"],
-                    ratios: Some(vec![("", 0.453592)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Ounce",
-                    abbr: "oz",
-                    aliases: &["ounce", "ounces"],
-                    ratios: Some(vec![("", 0.0283495)]),
-                    formula: None,
-                },
-            ]
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Gram".to_string(),
+                    abbr: "gm".to_string(),
+                    aliases: strings(&["g", "gram", "grams"]),
+                    dimension: Dimension::MASS,
+                    ratios: ratios(&[("", 0.001)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["g"])),
+                },
+                Unit {
+                    name: "Milligram".to_string(),
+                    abbr: "mg".to_string(),
+                    aliases: strings(&["milligram", "milligrams"]),
+                    dimension: Dimension::MASS,
+                    ratios: ratios(&[("", 1e-6)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Microgram".to_string(),
+                    abbr: "µg".to_string(),
+                    aliases: strings(&["microgram", "micrograms"]),
+                    dimension: Dimension::MASS,
+                    ratios: ratios(&[("", 1e-9)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Stone".to_string(),
+                    abbr: "st".to_string(),
+                    aliases: strings(&["stone", "stones"]),
+                    dimension: Dimension::MASS,
+                    ratios: ratios(&[("", 14.0 * POUND)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Pound".to_string(),
+                    abbr: "lb".to_string(),
+                    aliases: strings(&["pound", "pounds"]),
+                    dimension: Dimension::MASS,
+                    ratios: ratios(&[("", POUND)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Ounce".to_string(),
+                    abbr: "oz".to_string(),
+                    aliases: strings(&["ounce", "ounces"]),
+                    dimension: Dimension::MASS,
+                    ratios: ratios(&[("", POUND / 16.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+            ],
         ),
         (
             "PLANE ANGLE",
             vec![
                 Unit {
-                    name: "Radian",
-                    abbr: "rad",
-                    aliases: &["rads", "r", "radian", "radians"],
-                    ratios: Some(vec![("", 1.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Degree",
-                    abbr: "°",
-                    aliases: &["d", "degree", "degrees"],
-                    ratios: Some(vec![("", 0.0174533)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Gradian",
-                    abbr: "ᵍ",
-                    aliases: &[
+                    name: "Radian".to_string(),
+                    abbr: "rad".to_string(),
+                    aliases: strings(&["rads", "r", "radian", "radians"]),
+                    dimension: Dimension::NONE,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["rad"])),
+                },
+                Unit {
+                    name: "Degree".to_string(),
+                    abbr: "°".to_string(),
+                    aliases: strings(&["d", "degree", "degrees"]),
+                    dimension: Dimension::NONE,
+                    ratios: ratios(&[("", PI / 180.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Gradian".to_string(),
+                    abbr: "ᵍ".to_string(),
+                    aliases: strings(&[
                         "grad", "grads", "gradian", "gradians", "gr", "grs", "grd", "grds", "gon",
-                        "gons", "grade", "grades"
-                    ],
-                    ratios: Some(vec![("", 0.015708)]),
-                    formula: None,
+                        "gons", "grade", "grades",
+                    ]),
+                    dimension: Dimension::NONE,
+                    ratios: ratios(&[("", PI / 200.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
                 },
                 Unit {
-                    name: "Milliradian",
-                    abbr: "mrad",
-                    aliases: &[
+                    name: "Milliradian".to_string(),
+                    abbr: "mrad".to_string(),
+                    aliases: strings(&[
                         "mrads",
                         "mr",
                         "mrs",
                         "mil",
                         "mils",
                         "milliradian",
-                        "milliradians"
-                    ],
-                    ratios: Some(vec![("", 0.001)]),
-                    formula: None,
+                        "milliradians",
+                    ]),
+                    dimension: Dimension::NONE,
+                    ratios: ratios(&[("", 0.001)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
                 },
                 Unit {
-                    name: "Minute of Arc",
-                    abbr: "arcmin",
-                    aliases: &[
+                    name: "Minute of Arc".to_string(),
+                    abbr: "arcmin".to_string(),
+                    aliases: strings(&[
                         "minute of arc",
                         "minutes of arc",
                         "minute arc",
@@ -588,15 +1631,18 @@ lazy_static! {
                         "arcmin",
                         "arcmins",
                         "ma",
-                        "am"
-                    ],
-                    ratios: Some(vec![("", 0.000290888)]),
-                    formula: None,
+                        "am",
+                    ]),
+                    dimension: Dimension::NONE,
+                    ratios: ratios(&[("", PI / 10_800.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
                 },
                 Unit {
-                    name: "Second of Arc",
-                    abbr: "arcsec",
-                    aliases: &[
+                    name: "Second of Arc".to_string(),
+                    abbr: "arcsec".to_string(),
+                    aliases: strings(&[
                         "second of arc",
                         "seconds of arc",
                         "second arc",
@@ -608,193 +1654,674 @@ lazy_static! {
                         "arcsec",
                         "arcsecs",
                         "sa",
-                        "as"
-                    ],
-                    ratios: Some(vec![("", 4.8481e-6)]),
-                    formula: None,
+                        "as",
+                    ]),
+                    dimension: Dimension::NONE,
+                    ratios: ratios(&[("", PI / 648_000.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
                 },
-            ]
+            ],
         ),
         (
-            "PRESSURE",
+            "POWER",
             vec![
                 Unit {
-                    name: "Pascal",
-                    abbr: "Pa",
-                    aliases: &["pascal", "pascals"],
-                    ratios: Some(vec![("", 1.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Bar",
-                    abbr: "bar",
-                    aliases: &["bars"],
-                    ratios: Some(vec![("", 100000.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Pound-Force per Square Inch",
-                    abbr: "psi",
-                    aliases: &["psis", "lbf/in2", "pound-force per square inch"],
-                    ratios: Some(vec![("", 6894.76)]),
-                    formula: None,
-                },
+                    name: "Watt".to_string(),
+                    abbr: "W".to_string(),
+                    aliases: strings(&["watt", "watts"]),
+                    dimension: Dimension::POWER,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["W"])),
+                },
+                // the mechanical (imperial), metric and electrical horsepower
+                Unit {
+                    name: "Horsepower".to_string(),
+                    abbr: "hp".to_string(),
+                    aliases: strings(&["horsepower", "horsepowers"]),
+                    dimension: Dimension::POWER,
+                    ratios: ratios(&[
+                        ("Mechanical", 550.0 * FOOT * POUND_FORCE),
+                        ("Metric", 75.0 * STANDARD_GRAVITY),
+                        ("Electrical", Definition::from(746.0)),
+                    ]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "BTU per Hour".to_string(),
+                    abbr: "BTU/h".to_string(),
+                    aliases: strings(&["btu/h", "btu/hr", "btus per hour", "btu per hour"]),
+                    dimension: Dimension::POWER,
+                    ratios: ratios(&[("", BTU / 3600.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                // 12000 BTU/h (the heat to melt a short ton of ice in a day)
+                Unit {
+                    name: "Ton of Refrigeration".to_string(),
+                    abbr: "TR".to_string(),
+                    aliases: strings(&["ton of refrigeration", "tons of refrigeration", "RT"]),
+                    dimension: Dimension::POWER,
+                    ratios: ratios(&[("", 12_000.0 * BTU / 3600.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Erg per Second".to_string(),
+                    abbr: "erg/s".to_string(),
+                    aliases: strings(&["ergs per second", "erg per second"]),
+                    dimension: Dimension::POWER,
+                    ratios: ratios(&[("", 1e-7)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                // power levels in decibels relative to 1 mW and 1 W
+                Unit {
+                    name: "Decibel-Milliwatt".to_string(),
+                    abbr: "dBm".to_string(),
+                    aliases: strings(&["dbm", "decibel-milliwatt", "decibel-milliwatts"]),
+                    dimension: Dimension::POWER,
+                    ratios: ratios(&[("", 1e-3)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Decibel,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Decibel-Watt".to_string(),
+                    abbr: "dBW".to_string(),
+                    aliases: strings(&["dbw", "decibel-watt", "decibel-watts"]),
+                    dimension: Dimension::POWER,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Decibel,
+                    prefixes: Prefixes::None,
+                },
+            ],
+        ),
+        (
+            "PRESSURE",
+            vec![
                 Unit {
-                    name: "Standard Atmosphere",
-                    abbr: "atm",
-                    aliases: &["atms", "standard atmosphere", "standard atmospheres"],
-                    ratios: Some(vec![("", 101325.0)]),
-                    formula: None,
-                },
+                    name: "Pascal".to_string(),
+                    abbr: "Pa".to_string(),
+                    aliases: strings(&["pascal", "pascals"]),
+                    dimension: Dimension::PRESSURE,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["Pa"])),
+                },
+                Unit {
+                    name: "Bar".to_string(),
+                    abbr: "bar".to_string(),
+                    aliases: strings(&["bars"]),
+                    dimension: Dimension::PRESSURE,
+                    ratios: ratios(&[("", 100000.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["bar"])),
+                },
+                Unit {
+                    name: "Pound-Force per Square Inch".to_string(),
+                    abbr: "psi".to_string(),
+                    aliases: strings(&["psis", "lbf/in2", "pound-force per square inch"]),
+                    dimension: Dimension::PRESSURE,
+                    ratios: ratios(&[("", POUND_FORCE / (INCH * INCH))]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Standard Atmosphere".to_string(),
+                    abbr: "atm".to_string(),
+                    aliases: strings(&["atms", "standard atmosphere", "standard atmospheres"]),
+                    dimension: Dimension::PRESSURE,
+                    ratios: ratios(&[("", STANDARD_ATMOSPHERE)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Torr".to_string(),
+                    abbr: "Torr".to_string(),
+                    aliases: strings(&["torrs"]),
+                    dimension: Dimension::PRESSURE,
+                    ratios: ratios(&[("", STANDARD_ATMOSPHERE / 760.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+            ],
+        ),
+        (
+            "SPEED",
+            vec![
                 Unit {
-                    name: "Torr",
-                    abbr: "Torr",
-                    aliases: &["torrs"],
-                    ratios: Some(vec![("", 133.322)]),
-                    formula: None,
-                },
-            ]
+                    name: "Meter per Second".to_string(),
+                    abbr: "m/s".to_string(),
+                    aliases: strings(&[
+                        "mps",
+                        "meter per second",
+                        "meters per second",
+                        "metre per second",
+                        "metres per second",
+                    ]),
+                    dimension: Dimension::SPEED,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Kilometer per Hour".to_string(),
+                    abbr: "km/h".to_string(),
+                    aliases: strings(&[
+                        "kph",
+                        "kmh",
+                        "kilometer per hour",
+                        "kilometers per hour",
+                        "kilometre per hour",
+                        "kilometres per hour",
+                    ]),
+                    dimension: Dimension::SPEED,
+                    ratios: ratios(&[("", Definition::from(1.0) / 3.6)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Mile per Hour".to_string(),
+                    abbr: "mph".to_string(),
+                    aliases: strings(&["mi/h", "mile per hour", "miles per hour"]),
+                    dimension: Dimension::SPEED,
+                    ratios: ratios(&[("", MILE / 3600.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Knot".to_string(),
+                    abbr: "kn".to_string(),
+                    aliases: strings(&["kt", "knot", "knots"]),
+                    dimension: Dimension::SPEED,
+                    ratios: ratios(&[("", NAUTICAL_MILE / 3600.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Foot per Second".to_string(),
+                    abbr: "ft/s".to_string(),
+                    aliases: strings(&["fps", "foot per second", "feet per second"]),
+                    dimension: Dimension::SPEED,
+                    ratios: ratios(&[("", FOOT)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                // the speed of sound in dry air at sea level (ISA, 15 °C; not exact: a model value)
+                Unit {
+                    name: "Mach (sea level, 15 °C)".to_string(),
+                    abbr: "Ma".to_string(),
+                    aliases: strings(&["mach"]),
+                    dimension: Dimension::SPEED,
+                    ratios: ratios(&[("", 340.294)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Speed of Light".to_string(),
+                    abbr: "c".to_string(),
+                    aliases: strings(&["speed of light"]),
+                    dimension: Dimension::SPEED,
+                    ratios: ratios(&[("", SPEED_OF_LIGHT)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                // paces: the time to cover a distance (inverse units)
+                Unit {
+                    name: "Minute per Kilometer".to_string(),
+                    abbr: "min/km".to_string(),
+                    aliases: strings(&[
+                        "minute per kilometer",
+                        "minutes per kilometer",
+                        "minute per kilometre",
+                        "minutes per kilometre",
+                    ]),
+                    dimension: Dimension::SPEED,
+                    ratios: ratios(&[("", Definition::from(1000.0) / 60.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Inverse,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Minute per Mile".to_string(),
+                    abbr: "min/mi".to_string(),
+                    aliases: strings(&["minute per mile", "minutes per mile"]),
+                    dimension: Dimension::SPEED,
+                    ratios: ratios(&[("", MILE / 60.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Inverse,
+                    prefixes: Prefixes::None,
+                },
+            ],
         ),
         (
             "TEMPERATURE",
+            // affine units: the value in kelvins is the value of the unit times the ratio plus the offset
             vec![
                 Unit {
-                    name: "Kelvin",
-                    abbr: "K",
-                    aliases: &["k", "kelvin"],
-                    ratios: None,
-                    formula: Some(|u: &Unit, v: f64| -> Result<f64, String> {
-                        match u.abbr {
-                            "°F" => return Ok((v - 32.0) * 5.0 / 9.0 + 273.15),
-                            "°C" => return Ok(v + 273.15),
-                            _ => return Err(u.abbr.to_string()),
-                        }
-                    })
-                },
-                Unit {
-                    name: "Celsius",
-                    abbr: "°C",
-                    aliases: &["c", "celsius"],
-                    ratios: None,
-                    formula: Some(|u: &Unit, v: f64| -> Result<f64, String> {
-                        match u.abbr {
-                            "K" => return Ok(v - 273.15),
-                            "°F" => return Ok((v - 32.0) * 5.0 / 9.0),
-                            _ => return Err(u.abbr.to_string()),
-                        }
-                    })
-                },
-                Unit {
-                    name: "Fahrenheit",
-                    abbr: "°F",
-                    aliases: &["f", "fahrenheit"],
-                    ratios: None,
-                    formula: Some(|u: &Unit, v: f64| -> Result<f64, String> {
-                        match u.abbr {
-                            "K" => return Ok((v - 273.15) * 9.0 / 5.0 + 32.0),
-                            "°C" => return Ok(v * 9.0 / 5.0 + 32.0),
-                            _ => return Err(u.abbr.to_string()),
-                        }
-                    })
-                },
-            ]
+                    name: "Kelvin".to_string(),
+                    abbr: "K".to_string(),
+                    aliases: strings(&["k", "kelvin"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Celsius".to_string(),
+                    abbr: "°C".to_string(),
+                    aliases: strings(&["c", "celsius"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::from(273.15),
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Fahrenheit".to_string(),
+                    abbr: "°F".to_string(),
+                    aliases: strings(&["f", "fahrenheit"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", Definition::from(5.0) / 9.0)]),
+                    offset: Definition::from(459.67) * 5.0 / 9.0,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Rankine".to_string(),
+                    abbr: "°R".to_string(),
+                    aliases: strings(&["°Ra", "rankine"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", Definition::from(5.0) / 9.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Réaumur".to_string(),
+                    abbr: "°Ré".to_string(),
+                    aliases: strings(&["°Re", "réaumur", "reaumur"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", 1.25)]),
+                    offset: Definition::from(273.15),
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                // 0 °Rø is 7.5 °C below the freezing point of water
+                Unit {
+                    name: "Rømer".to_string(),
+                    abbr: "°Rø".to_string(),
+                    aliases: strings(&["°Ro", "rømer", "romer"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", Definition::from(40.0) / 21.0)]),
+                    offset: Definition::from(273.15) - Definition::from(7.5) * 40.0 / 21.0,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                // 0 °De is the boiling point of water, and the scale goes down
+                Unit {
+                    name: "Delisle".to_string(),
+                    abbr: "°De".to_string(),
+                    aliases: strings(&["delisle"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", Definition::from(-2.0) / 3.0)]),
+                    offset: Definition::from(373.15),
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Newton".to_string(),
+                    abbr: "°N".to_string(),
+                    aliases: strings(&["newton degree", "newton degrees"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", Definition::from(100.0) / 33.0)]),
+                    offset: Definition::from(273.15),
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+            ],
         ),
         (
-            "TIME",
+            "TEMPERATURE DIFFERENCE",
+            // the differences of temperatures (e.g. a rise of 10 Δ°C is a rise of 18 Δ°F)
             vec![
                 Unit {
-                    name: "Second",
-                    abbr: "s",
-                    aliases: &["sec", "second", "seconds"],
-                    ratios: Some(vec![("", 1.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Nanosecond",
-                    abbr: "ns",
-                    aliases: &["nanosecond", "nanoseconds"],
-                    ratios: Some(vec![("", 1e-9)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Microsecond",
-                    abbr: "μs",
-                    aliases: &["microsecond", "microseconds"],
-                    ratios: Some(vec![("", 1e-6)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Millisecond",
-                    abbr: "ms",
-                    aliases: &["millisecond", "milliseconds"],
-                    ratios: Some(vec![("", 0.001)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Minute",
-                    abbr: "min",
-                    aliases: &["m", "mins", "minute", "minutes"],
-                    ratios: Some(vec![("", 60.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Hour",
-                    abbr: "hr",
-                    aliases: &["h", "hrs", "hour", "hours"],
-                    ratios: Some(vec![("", 3600.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Day",
-                    abbr: "d",
-                    aliases: &["day", "days"],
-                    ratios: Some(vec![("", 86400.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Week",
-                    abbr: "wk",
-                    aliases: &["w", "week", "weeks"],
-                    ratios: Some(vec![("", 604800.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Month",
-                    abbr: "mth",
-                    aliases: &["m", "month", "months"],
-                    ratios: Some(vec![("", 2629746.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Year",
-                    abbr: "yr",
-                    aliases: &["y", "yrs", "year", "years"],
-                    ratios: Some(vec![("", 31556952.0)]),
-                    formula: None,
-                },
+                    name: "Kelvin Difference".to_string(),
+                    abbr: "ΔK".to_string(),
+                    aliases: strings(&["delta K", "delta kelvin"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Celsius Difference".to_string(),
+                    abbr: "Δ°C".to_string(),
+                    aliases: strings(&["delta °C", "delta C", "delta celsius"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Fahrenheit Difference".to_string(),
+                    abbr: "Δ°F".to_string(),
+                    aliases: strings(&["delta °F", "delta F", "delta fahrenheit"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", Definition::from(5.0) / 9.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Rankine Difference".to_string(),
+                    abbr: "Δ°R".to_string(),
+                    aliases: strings(&["delta °R", "delta rankine"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", Definition::from(5.0) / 9.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Réaumur Difference".to_string(),
+                    abbr: "Δ°Ré".to_string(),
+                    aliases: strings(&["delta °Ré", "delta réaumur", "delta reaumur"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", 1.25)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Rømer Difference".to_string(),
+                    abbr: "Δ°Rø".to_string(),
+                    aliases: strings(&["delta °Rø", "delta rømer", "delta romer"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", Definition::from(40.0) / 21.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Delisle Difference".to_string(),
+                    abbr: "Δ°De".to_string(),
+                    aliases: strings(&["delta °De", "delta delisle"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", Definition::from(-2.0) / 3.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Newton Difference".to_string(),
+                    abbr: "Δ°N".to_string(),
+                    aliases: strings(&["delta °N"]),
+                    dimension: Dimension::TEMPERATURE,
+                    ratios: ratios(&[("", Definition::from(100.0) / 33.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+            ],
+        ),
+        (
+            "TIME",
+            vec![
                 Unit {
-                    name: "Decade",
-                    abbr: "dec",
-                    aliases: &["d", "decs", "decade", "decades"],
-                    ratios: Some(vec![("", 315569520.0)]),
-                    formula: None,
-                },
+                    name: "Second".to_string(),
+                    abbr: "s".to_string(),
+                    aliases: strings(&["sec", "second", "seconds"]),
+                    dimension: Dimension::TIME,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["s"])),
+                },
+                Unit {
+                    name: "Nanosecond".to_string(),
+                    abbr: "ns".to_string(),
+                    aliases: strings(&["nanosecond", "nanoseconds"]),
+                    dimension: Dimension::TIME,
+                    ratios: ratios(&[("", 1e-9)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Microsecond".to_string(),
+                    abbr: "μs".to_string(),
+                    aliases: strings(&["microsecond", "microseconds"]),
+                    dimension: Dimension::TIME,
+                    ratios: ratios(&[("", 1e-6)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Millisecond".to_string(),
+                    abbr: "ms".to_string(),
+                    aliases: strings(&["millisecond", "milliseconds"]),
+                    dimension: Dimension::TIME,
+                    ratios: ratios(&[("", 0.001)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Minute".to_string(),
+                    abbr: "min".to_string(),
+                    aliases: strings(&["m", "mins", "minute", "minutes"]),
+                    dimension: Dimension::TIME,
+                    ratios: ratios(&[("", 60.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Hour".to_string(),
+                    abbr: "hr".to_string(),
+                    aliases: strings(&["h", "hrs", "hour", "hours"]),
+                    dimension: Dimension::TIME,
+                    ratios: ratios(&[("", 3600.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Day".to_string(),
+                    abbr: "d".to_string(),
+                    aliases: strings(&["day", "days"]),
+                    dimension: Dimension::TIME,
+                    ratios: ratios(&[("", DAY)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Week".to_string(),
+                    abbr: "wk".to_string(),
+                    aliases: strings(&["w", "week", "weeks"]),
+                    dimension: Dimension::TIME,
+                    ratios: ratios(&[("", 7.0 * DAY)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Month".to_string(),
+                    abbr: "mth".to_string(),
+                    aliases: strings(&["month", "months"]),
+                    dimension: Dimension::TIME,
+                    ratios: ratios(&[("", GREGORIAN_YEAR / 12.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Year".to_string(),
+                    abbr: "yr".to_string(),
+                    aliases: strings(&["y", "yrs", "year", "years"]),
+                    dimension: Dimension::TIME,
+                    ratios: ratios(&[("", GREGORIAN_YEAR)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Decade".to_string(),
+                    abbr: "dec".to_string(),
+                    aliases: strings(&["decs", "decade", "decades"]),
+                    dimension: Dimension::TIME,
+                    ratios: ratios(&[("", 10.0 * GREGORIAN_YEAR)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Century".to_string(),
+                    abbr: "cent".to_string(),
+                    aliases: strings(&["c", "century", "centuries"]),
+                    dimension: Dimension::TIME,
+                    ratios: ratios(&[("", 100.0 * GREGORIAN_YEAR)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+            ],
+        ),
+        (
+            // torques have the dimension of energies but are a distinct unit type,
+            // so they don't convert into energies (e.g. "N⋅m" into "J")
+            "TORQUE",
+            vec![
                 Unit {
-                    name: "Century",
-                    abbr: "cent",
-                    aliases: &["c", "century", "centuries"],
-                    ratios: Some(vec![("", 3155695200.0)]),
-                    formula: None,
+                    name: "Newton-Meter".to_string(),
+                    abbr: "N⋅m".to_string(),
+                    aliases: strings(&[
+                        "N*m",
+                        "N·m",
+                        "Nm",
+                        "newton-meter",
+                        "newton-meters",
+                        "newton-metre",
+                        "newton-metres",
+                        "newton meter",
+                        "newton meters",
+                        "newton metre",
+                        "newton metres",
+                    ]),
+                    dimension: Dimension::ENERGY,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["N⋅m"])),
+                },
+                Unit {
+                    name: "Pound-Force Foot".to_string(),
+                    abbr: "lbf⋅ft".to_string(),
+                    aliases: strings(&[
+                        "lbf*ft",
+                        "lbf·ft",
+                        "lbfft",
+                        "lb⋅ft",
+                        "lbft",
+                        "pound-foot",
+                        "pound-feet",
+                        "pound-force foot",
+                        "pound-force feet",
+                    ]),
+                    dimension: Dimension::ENERGY,
+                    ratios: ratios(&[("", POUND_FORCE * FOOT)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Pound-Force Inch".to_string(),
+                    abbr: "lbf⋅in".to_string(),
+                    aliases: strings(&[
+                        "lbf*in",
+                        "lbf·in",
+                        "lbfin",
+                        "lb⋅in",
+                        "lbin",
+                        "pound-inch",
+                        "pound-inches",
+                        "pound-force inch",
+                        "pound-force inches",
+                    ]),
+                    dimension: Dimension::ENERGY,
+                    ratios: ratios(&[("", POUND_FORCE * INCH)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Kilogram-Force Meter".to_string(),
+                    abbr: "kgf⋅m".to_string(),
+                    aliases: strings(&[
+                        "kgf*m",
+                        "kgf·m",
+                        "kgfm",
+                        "kilogram-force meter",
+                        "kilogram-force meters",
+                        "kilogram-force metre",
+                        "kilogram-force metres",
+                    ]),
+                    dimension: Dimension::ENERGY,
+                    ratios: ratios(&[("", STANDARD_GRAVITY)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
                 },
-            ]
+            ],
+        ),
+        (
+            "VOLTAGE",
+            vec![Unit {
+                name: "Volt".to_string(),
+                abbr: "V".to_string(),
+                aliases: strings(&["volt", "volts"]),
+                dimension: Dimension::VOLTAGE,
+                ratios: ratios(&[("", 1.0)]),
+                offset: Definition::ZERO,
+                scale: Scale::Linear,
+                prefixes: Prefixes::Si(strings(&["V"])),
+            }],
         ),
         (
             "VOLUME",
             vec![
                 Unit {
-                    name: "Cubic Meter",
-                    abbr: "m³",
-                    aliases: &[
+                    name: "Cubic Meter".to_string(),
+                    abbr: "m³".to_string(),
+                    aliases: strings(&[
                         "m3",
                         "meter3",
                         "meters3",
@@ -803,93 +2330,140 @@ lazy_static! {
                         "cubic meter",
                         "cubic meters",
                         "cubic metre",
-                        "cubic metres"
-                    ],
-                    ratios: Some(vec![("", 1.0)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Liter",
-                    abbr: "l",
-                    aliases: &["liter", "liters", "litre", "litres"],
-                    ratios: Some(vec![("", 0.001)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Milliliter",
-                    abbr: "ml",
-                    aliases: &["milliliter", "milliliters", "millilitre", "millilitres"],
-                    ratios: Some(vec![("", 1e-6)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Gallon • Imperial / US liquid",
-                    abbr: "gal",
-                    aliases: &["gallon", "gallons"],
-                    ratios: Some(vec![("Imperial", 0.00454609), ("US liquid", 0.00378541)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Quart • Imperial / US liquid",
-                    abbr: "qt",
-                    aliases: &["quart", "quarts"],
-                    ratios: Some(vec![("Imperial", 0.00113652), ("US liquid", 0.000946353)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Pint • Imperial / US liquid",
-                    abbr: "pt",
-                    aliases: &["pint", "pints"],
-                    ratios: Some(vec![("Imperial", 0.000568261), ("US liquid", 0.000473176)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Cup • Imperial / US legal",
-                    abbr: "c",
-                    aliases: &["cup", "cups"],
-                    ratios: Some(vec![("Imperial", 0.000284131), ("US legal", 0.00024)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Fluid Ounce • Imperial / US",
-                    abbr: "fl oz",
-                    aliases: &[
-                        "floz",
-                        "fluid ounce",
-                        "fluid ounces"
-                    ],
-                    ratios: Some(vec![("Imperial", 2.8413e-5), ("US", 2.9574e-5)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Tablespoon • Imperial / US",
-                    abbr: "tbsp",
-                    aliases: &["tablespoon", "tablespoons"],
-                    ratios: Some(vec![("Imperial", 1.7758e-5), ("US", 1.4787e-5)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Teaspoon • Imperial / US",
-                    abbr: "tsp",
-                    aliases: &["teaspoon", "teaspoons"],
-                    ratios: Some(vec![("Imperial", 5.9194e-6), ("US", 4.9289e-6)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Cubic Foot",
-                    abbr: "ft³",
-                    aliases: &["ft3", "cu ft", "cubic foot", "cubic feet"],
-                    ratios: Some(vec![("", 0.0283168)]),
-                    formula: None,
-                },
-                Unit {
-                    name: "Cubic Inch",
-                    abbr: "in³",
-                    aliases: &["in3", "cu in", "cubic inch", "cubic inches"],
-                    ratios: Some(vec![("", 1.6387e-5)]),
-                    formula: None,
-                },
-            ]
+                        "cubic metres",
+                    ]),
+                    dimension: Dimension::VOLUME,
+                    ratios: ratios(&[("", 1.0)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Liter".to_string(),
+                    abbr: "l".to_string(),
+                    aliases: strings(&["liter", "liters", "litre", "litres"]),
+                    dimension: Dimension::VOLUME,
+                    ratios: ratios(&[("", 0.001)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::Si(strings(&["l", "L"])),
+                },
+                Unit {
+                    name: "Milliliter".to_string(),
+                    abbr: "ml".to_string(),
+                    aliases: strings(&["milliliter", "milliliters", "millilitre", "millilitres"]),
+                    dimension: Dimension::VOLUME,
+                    ratios: ratios(&[("", 1e-6)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Gallon • Imperial / US liquid".to_string(),
+                    abbr: "gal".to_string(),
+                    aliases: strings(&["gallon", "gallons"]),
+                    dimension: Dimension::VOLUME,
+                    ratios: ratios(&[("Imperial", IMPERIAL_GALLON), ("US liquid", US_GALLON)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Quart • Imperial / US liquid".to_string(),
+                    abbr: "qt".to_string(),
+                    aliases: strings(&["quart", "quarts"]),
+                    dimension: Dimension::VOLUME,
+                    ratios: ratios(&[
+                        ("Imperial", IMPERIAL_GALLON / 4.0),
+                        ("US liquid", US_GALLON / 4.0),
+                    ]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Pint • Imperial / US liquid".to_string(),
+                    abbr: "pt".to_string(),
+                    aliases: strings(&["pint", "pints"]),
+                    dimension: Dimension::VOLUME,
+                    ratios: ratios(&[
+                        ("Imperial", IMPERIAL_GALLON / 8.0),
+                        ("US liquid", US_GALLON / 8.0),
+                    ]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Cup • Imperial / US legal".to_string(),
+                    abbr: "c".to_string(),
+                    aliases: strings(&["cup", "cups"]),
+                    dimension: Dimension::VOLUME,
+                    ratios: ratios(&[
+                        ("Imperial", IMPERIAL_GALLON / 16.0),
+                        ("US legal", Definition::from(240e-6)),
+                    ]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Fluid Ounce • Imperial / US".to_string(),
+                    abbr: "fl oz".to_string(),
+                    aliases: strings(&["floz", "fluid ounce", "fluid ounces"]),
+                    dimension: Dimension::VOLUME,
+                    ratios: ratios(&[("Imperial", IMPERIAL_FLUID_OUNCE), ("US", US_FLUID_OUNCE)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Tablespoon • Imperial / US".to_string(),
+                    abbr: "tbsp".to_string(),
+                    aliases: strings(&["tablespoon", "tablespoons"]),
+                    dimension: Dimension::VOLUME,
+                    ratios: ratios(&[
+                        ("Imperial", IMPERIAL_FLUID_OUNCE * 0.625),
+                        ("US", US_FLUID_OUNCE / 2.0),
+                    ]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Teaspoon • Imperial / US".to_string(),
+                    abbr: "tsp".to_string(),
+                    aliases: strings(&["teaspoon", "teaspoons"]),
+                    dimension: Dimension::VOLUME,
+                    ratios: ratios(&[
+                        ("Imperial", IMPERIAL_FLUID_OUNCE * 0.625 / 3.0),
+                        ("US", US_FLUID_OUNCE / 6.0),
+                    ]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Cubic Foot".to_string(),
+                    abbr: "ft³".to_string(),
+                    aliases: strings(&["ft3", "cu ft", "cubic foot", "cubic feet"]),
+                    dimension: Dimension::VOLUME,
+                    ratios: ratios(&[("", FOOT * FOOT * FOOT)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+                Unit {
+                    name: "Cubic Inch".to_string(),
+                    abbr: "in³".to_string(),
+                    aliases: strings(&["in3", "cu in", "cubic inch", "cubic inches"]),
+                    dimension: Dimension::VOLUME,
+                    ratios: ratios(&[("", INCH * INCH * INCH)]),
+                    offset: Definition::ZERO,
+                    scale: Scale::Linear,
+                    prefixes: Prefixes::None,
+                },
+            ],
         ),
-    ];
+    ]
 }
@@ -1,17 +1,86 @@
-use crate::units::{Unit, DEFAULT_PRECISION, MAX_PRECISION, UNITS};
+use crate::error::ConvertError;
+#[cfg(feature = "exact")]
+use crate::exact::{parse_decimal, Rational};
+use crate::units::{DEFAULT_PRECISION, MAX_PRECISION};
+#[cfg(feature = "exact")]
+use num_traits::Zero;
 use regex::Regex;
 
+// a (optionally signed) decimal number with an optional exponent, e.g. "5", "-3.14", "+.5", "6.02e23", "1.5E-9"
+macro_rules! number_pattern {
+    () => {
+        r"[+-]?(?:\d+\.?\d*|\.\d+)(?:[eE][+-]?\d+)?"
+    };
+}
+
+// an unsigned number, used as the divisor of a fraction (e.g. the "2" in "1/2")
+macro_rules! unsigned_number_pattern {
+    () => {
+        r"(?:\d+\.?\d*|\.\d+)(?:[eE][+-]?\d+)?"
+    };
+}
+
+// a sexagesimal value (minutes and seconds or hours, minutes and seconds), e.g. "5:30" or "1:05:30.5"
+macro_rules! sexagesimal_pattern {
+    () => {
+        r"\d+(?::[0-5]\d(?:\.\d+)?)+"
+    };
+}
+
+lazy_static! {
+    // a value (a sexagesimal value, a number or a fraction) followed by a unit
+    static ref VALUE_REGEX: Regex = Regex::new(concat!(
+        r"^\s*(",
+        sexagesimal_pattern!(),
+        r"|",
+        number_pattern!(),
+        r"(?:/",
+        unsigned_number_pattern!(),
+        r")?)(.*)$"
+    ))
+    .unwrap();
+    // a value-unit pair within a list of space separated pairs:
+    // the unit can't contain digits (other than a single trailing one, e.g. "m2", an exponent, e.g. "s^-2",
+    // or a divisor, e.g. "L/100km"), signs or dots
+    static ref VALUE_UNIT_PAIR_REGEX: Regex = Regex::new(concat!(
+        r"((?:",
+        sexagesimal_pattern!(),
+        r"|",
+        number_pattern!(),
+        r"(?:/",
+        unsigned_number_pattern!(),
+        r")?)(\s*(?:\^-?\d+|/\d+|[^\d\s+\-.]\d{0,1}))+)"
+    ))
+    .unwrap();
+}
+
+// split a list of value-unit pairs (e.g. "5ft 10 in") into separate pairs (e.g. ["5ft", "10 in"])
+pub fn split_value_unit_pairs(value_unit_pairs_str: &str) -> Vec<&str> {
+    VALUE_UNIT_PAIR_REGEX
+        .captures_iter(value_unit_pairs_str)
+        .map(|cap| cap.get(1).unwrap().as_str().trim())
+        .collect()
+}
+
 // parse a value-unit pair and return the value and unit
-pub fn parse_value_unit(value_unit_str: &str) -> Option<(f64, String)> {
-    let value_regex = Regex::new(r"(-?[\d/.]+)(.*)").unwrap();
-    let value_captures_opt = value_regex.captures(value_unit_str);
+pub fn parse_value_unit(value_unit_str: &str) -> Result<(f64, String), ConvertError> {
+    let invalid_value = || ConvertError::InvalidValue(value_unit_str.to_string());
+    let value_captures_opt = VALUE_REGEX.captures(value_unit_str);
 
     if let Some(value_captures) = value_captures_opt {
         if value_captures.len() == 3 {
             let value_str = value_captures.get(1).unwrap().as_str().trim();
             let mut value: Option<f64> = None;
 
-            if value_str.contains("/") {
+            if value_str.contains(":") {
+                // (e.g. "5:30" is 5.5 and "1:05:30" is 1.0916...)
+                let mut parts = value_str.split(":").map(|p| p.parse::<f64>());
+                let mut sum = parts.next().unwrap().map_err(|_| invalid_value())?;
+                for (i, part) in parts.enumerate() {
+                    sum += part.map_err(|_| invalid_value())? / 60_f64.powi(i as i32 + 1);
+                }
+                value = Some(sum);
+            } else if value_str.contains("/") {
                 let dd: Vec<String> = value_str.split("/").map(|s| s.trim().to_string()).collect();
                 if dd.len() == 2 {
                     let dividend_result = dd[0].parse::<f64>();
@@ -20,72 +89,82 @@ pub fn parse_value_unit(value_unit_str: &str) -> Option<(f64, String)> {
                     if let (Ok(dividend), Ok(divisor)) = (dividend_result, divisor_result) {
                         value = Some(dividend / divisor);
                     } else {
-                        return None;
+                        return Err(invalid_value());
                     }
                 }
             } else {
                 let value_parse_result = value_str.parse::<f64>();
                 match value_parse_result {
                     Ok(num) => value = Some(num),
-                    Err(_) => return None,
+                    Err(_) => return Err(invalid_value()),
                 }
             }
 
             if let Some(v) = value {
+                if !v.is_finite() {
+                    return Err(ConvertError::NonFiniteValue(value_str.to_string()));
+                }
                 let unit = value_captures.get(2).unwrap().as_str().trim().to_string();
-                return Some((v, unit));
+                return Ok((v, unit));
             }
         }
     }
-    None
+    Err(invalid_value())
 }
 
-// find a unit in the UNITS collection and return its type and ratio
-pub fn find_unit(unit_str: &str, unit_type: Option<&str>) -> Option<(&'static str, &'static Unit)> {
-    for u_type in UNITS.iter() {
-        if let Some(ut) = unit_type {
-            if !u_type.0.eq_ignore_ascii_case(ut) {
-                continue;
-            }
-        }
-        // ============================================================
-        // separate full UNITS enum iteration is needed here
-        // to prioritize case-sensitive matching
-        // (e.g. to recognize "B" as bytes and "b" as bits)
-        // ============================================================
-        for unit in u_type.1.iter() {
-            // check exact match
-            if unit.abbr == unit_str {
-                return Some((u_type.0, unit));
-            }
+// parse the value of a value-unit pair as an exact rational number (see parse_value_unit),
+// e.g. "1/3" is exactly a third and "1:05:30" exactly 131/120; None if the value isn't valid or finite
+#[cfg(feature = "exact")]
+pub fn parse_exact_value(value_unit_str: &str) -> Option<Rational> {
+    let value_captures = VALUE_REGEX.captures(value_unit_str)?;
+    let value_str = value_captures.get(1).unwrap().as_str().trim();
+    if value_str.contains(':') {
+        let mut parts = value_str.split(':').map(parse_decimal);
+        let mut sum = parts.next()??;
+        let sixty = Rational::from_integer(60.into());
+        let mut place = Rational::from_integer(1.into());
+        for part in parts {
+            place /= &sixty;
+            sum += part? * &place;
         }
-        // ============================================================
-        let unit_lc = unit_str.to_lowercase();
-        for unit in u_type.1.iter() {
-            // check case-insensitive match
-            if unit.abbr.to_lowercase() == unit_lc {
-                return Some((u_type.0, unit));
-            }
-            // check aliases
-            for alias in unit.aliases {
-                if alias.to_lowercase() == unit_lc {
-                    return Some((u_type.0, unit));
-                }
-            }
+        Some(sum)
+    } else if let Some((dividend, divisor)) = value_str.split_once('/') {
+        let divisor = parse_decimal(divisor.trim()).filter(|d| !d.is_zero())?;
+        Some(parse_decimal(dividend.trim())? / divisor)
+    } else {
+        parse_decimal(value_str)
+    }
+}
+
+// parse a precision (an integer or an "*" to use max precision)
+pub fn parse_precision(precision_str: &str) -> Result<i32, ConvertError> {
+    if precision_str == "*" {
+        return Ok(MAX_PRECISION);
+    }
+    match precision_str.parse::<i32>() {
+        Ok(num) if num > MAX_PRECISION => {
+            Err(ConvertError::PrecisionTooHigh(precision_str.to_string()))
         }
+        Ok(num) if num >= 0 => Ok(num),
+        Ok(_) => Err(ConvertError::NegativePrecision(precision_str.to_string())),
+        Err(_) => Err(ConvertError::InvalidPrecision(precision_str.to_string())),
+    }
+}
+
+// format a (source) value as entered, using the scientific notation for very large or very small values
+pub fn format_value(value: &f64) -> String {
+    let abs = value.abs();
+    if abs != 0.0 && !(1e-6..1e15).contains(&abs) {
+        format!("{:e}", value)
+    } else {
+        value.to_string()
     }
-    None
 }
 
 // format a number with the specified precision
 pub fn format_number(num: &f64, precision: Option<i32>) -> String {
-    let mut prc = DEFAULT_PRECISION;
-    if precision.is_some() {
-        prc = precision.unwrap();
-        if prc > MAX_PRECISION {
-            prc = MAX_PRECISION;
-        }
-    };
-    let p = (10 as f64).powf(prc as f64);
-    format!("{}", (num * p).round() / p)
+    let prc = precision.unwrap_or(DEFAULT_PRECISION).min(MAX_PRECISION);
+    let p = 10_f64.powf(prc as f64);
+    // (adding 0.0 turns a negative zero, e.g. from rounding -0.001, into a positive one)
+    format!("{}", (num * p).round() / p + 0.0)
 }
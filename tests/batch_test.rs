@@ -0,0 +1,139 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+struct BatchOutput {
+    stdout: String,
+    stderr: String,
+    success: bool,
+}
+
+// run the batch mode with the given file argument (if any) and stdin
+fn run_batch(file: Option<&str>, stdin: &str) -> BatchOutput {
+    let mut args = vec!["--batch"];
+    args.extend(file);
+    run(&args, stdin)
+}
+
+// run the app with the given arguments and stdin
+fn run(args: &[&str], stdin: &str) -> BatchOutput {
+    let mut command = Command::new("cargo");
+    command.arg("run").arg("-q").arg("--").args(args);
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to spawn child process");
+    child
+        .stdin
+        .take()
+        .expect("Failed to open stdin")
+        .write_all(stdin.as_bytes())
+        .expect("Failed to write to stdin");
+    let output = child.wait_with_output().expect("Failed to wait on child");
+    BatchOutput {
+        stdout: String::from_utf8(output.stdout).expect("Failed to convert stdout to string"),
+        stderr: String::from_utf8(output.stderr).expect("Failed to convert stderr to string"),
+        success: output.status.success(),
+    }
+}
+
+#[test]
+fn test_batch_stdin() {
+    let output = run_batch(
+        None,
+        "5 ft = m\n\n# a comment\n1 gal = qt:1\n(3 ft + 4 in) * 2 to cm\n",
+    );
+    assert_eq!(
+        output.stdout,
+        "[LENGTH] 5 ft = 1.52 m\n\
+        [VOLUME] 1 gal (Imperial) = 4 qt (Imperial)\n\
+        [VOLUME] 1 gal (Imperial) = 4.8 qt (US liquid)\n\
+        [VOLUME] 1 gal (US liquid) = 3.3 qt (Imperial)\n\
+        [VOLUME] 1 gal (US liquid) = 4 qt (US liquid)\n\
+        [LENGTH] (3 ft + 4 in) * 2 = 203.2 cm\n"
+    );
+    assert_eq!(output.stderr, "");
+    assert!(output.success);
+}
+
+#[test]
+fn test_batch_file() {
+    let path = std::env::temp_dir().join(format!("cu_batch_test_{}.txt", std::process::id()));
+    std::fs::write(
+        &path,
+        "5 ft = m\r\n5 ft = foo\r\n1 m = cm:x\r\n10 kg = lb\r\n",
+    )
+    .unwrap();
+    let output = run_batch(Some(path.to_str().unwrap()), "");
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        output.stdout,
+        "[LENGTH] 5 ft = 1.52 m\n[LENGTH] 1 m = 100 cm\n[MASS] 10 kg = 22.05 lb\n"
+    );
+    assert_eq!(
+        output.stderr,
+        "line 2: [ Unknown unit: foo ]\n\
+        line 3: Not a valid precision: x (using the default precision of 2 instead)\n"
+    );
+    // a failed line fails the whole batch
+    assert!(!output.success);
+}
+
+#[test]
+fn test_batch_missing_file() {
+    let output = run_batch(Some("missing_batch_file.txt"), "");
+    assert_eq!(output.stdout, "");
+    assert!(output
+        .stderr
+        .starts_with("[ Can't read the file missing_batch_file.txt:"));
+    assert!(!output.success);
+}
+
+#[test]
+fn test_output_formats() {
+    let output = run(&["--format", "json", "5 ft 10 in to m"], "");
+    assert_eq!(
+        output.stdout,
+        "{\"category\":\"LENGTH\",\"source\":\"5 ft 10 in\",\
+        \"sources\":[{\"value\":5.0,\"unit\":\"ft\"},{\"value\":10.0,\"unit\":\"in\"}],\
+        \"source_variant\":null,\"target_abbr\":\"m\",\"target_name\":\"Meter\",\
+        \"target_variant\":null,\"value\":1.778,\"formatted\":\"1.78\"}\n"
+    );
+    assert!(output.success);
+
+    // warnings and errors go to stderr
+    let output = run(&["--format=tsv", "1 m = cm:x"], "");
+    assert_eq!(
+        output.stdout,
+        "category\tsource\tsources\tsource_variant\ttarget_abbr\ttarget_name\ttarget_variant\tvalue\tformatted\n\
+        LENGTH\t1 m\t1 m\t\tcm\tCentimeter\t\t100.0\t100\n"
+    );
+    assert_eq!(
+        output.stderr,
+        "Not a valid precision: x (using the default precision of 2 instead)\n"
+    );
+    assert!(output.success);
+
+    let output = run(&["--format", "json", "5 ft = foo"], "");
+    assert_eq!(output.stdout, "");
+    assert_eq!(output.stderr, "[ Unknown unit: foo ]\n");
+    assert!(!output.success);
+
+    let output = run(&["--format", "xml", "5 ft = m"], "");
+    assert_eq!(
+        output.stderr,
+        "[ Unknown output format: xml (use text, json or tsv) ]\n"
+    );
+    assert!(!output.success);
+
+    // the batch mode (with a single TSV header)
+    let output = run(&["--batch", "--format", "tsv"], "5 ft = m\n1 kg = g\n");
+    assert_eq!(
+        output.stdout,
+        "category\tsource\tsources\tsource_variant\ttarget_abbr\ttarget_name\ttarget_variant\tvalue\tformatted\n\
+        LENGTH\t5 ft\t5 ft\t\tm\tMeter\t\t1.524\t1.52\n\
+        MASS\t1 kg\t1 kg\t\tgm\tGram\t\t1000.0\t1000\n"
+    );
+    assert!(output.success);
+}
@@ -72,7 +72,7 @@ fn test_unit_conversion() {
             input: "12floz = ml",
             expected_output: "\
             [VOLUME] 12 fl oz (Imperial) = 340.96 ml\n\
-            [VOLUME] 12 fl oz (US) = 354.89 ml",
+            [VOLUME] 12 fl oz (US) = 354.88 ml",
             description: "Fluid ounces to milliliters",
         },
         TestCase {
@@ -87,6 +87,242 @@ fn test_unit_conversion() {
     run_tests(test_cases);
 }
 
+#[test]
+fn test_data_rate_units() {
+    let test_cases = vec![
+        TestCase {
+            input: "100 Mbps to MB/s",
+            expected_output: "[DATA RATE] 100 Mbps = 12.5 MB/s",
+            description: "Megabits per second to megabytes per second",
+        },
+        TestCase {
+            input: "1 GiB/s = Gbps",
+            expected_output: "[DATA RATE] 1 GiB/s = 8.59 Gbps",
+            description: "Gibibytes per second to gigabits per second",
+        },
+        TestCase {
+            input: "1 Tbps to Gbps",
+            expected_output: "[DATA RATE] 1 Tbps = 1000 Gbps",
+            description: "Prefixed bits per second",
+        },
+        TestCase {
+            input: "4 TB / 1 Gbps to hr",
+            expected_output: "[TIME] 4 TB / 1 Gbps = 8.89 hr",
+            description: "Transfer time of a size at a data rate",
+        },
+        TestCase {
+            input: "2 hr * 50 Mbps to GiB",
+            expected_output: "[DIGITAL STORAGE] 2 hr * 50 Mbps = 41.91 GiB",
+            description: "Transferred size at a data rate",
+        },
+    ];
+
+    run_tests(test_cases);
+}
+
+#[test]
+fn test_electrical_units() {
+    let test_cases = vec![
+        TestCase {
+            input: "500 mA to A",
+            expected_output: "[ELECTRIC CURRENT] 500 mA = 0.5 A",
+            description: "Milliamperes to amperes",
+        },
+        TestCase {
+            input: "4.7 kohm to Ω",
+            expected_output: "[ELECTRIC RESISTANCE] 4.7 kΩ = 4700 Ω",
+            description: "Kiloohms to ohms",
+        },
+        TestCase {
+            input: "1 μF to pF",
+            expected_output: "[ELECTRIC CAPACITANCE] 1 μF = 1000000 pF",
+            description: "Microfarads to picofarads",
+        },
+        TestCase {
+            input: "10 mH to μH",
+            expected_output: "[INDUCTANCE] 10 mH = 10000 μH",
+            description: "Millihenries to microhenries",
+        },
+        TestCase {
+            input: "2 Ah to C",
+            expected_output: "[ELECTRIC CHARGE] 2 Ah = 7200 C",
+            description: "Ampere-hours to coulombs",
+        },
+        TestCase {
+            input: "1 V / 1 A to Ω",
+            expected_output: "[ELECTRIC RESISTANCE] 1 V / 1 A = 1 Ω",
+            description: "Voltage over current to ohms",
+        },
+        TestCase {
+            input: "20 mS to S",
+            expected_output: "[ELECTRIC CONDUCTANCE] 20 mS = 0.02 S",
+            description: "Millisiemens to siemens",
+        },
+        TestCase {
+            input: "5000 mAh @ 3.7 V to W⋅h",
+            expected_output: "[ENERGY] 5000 mAh @ 3.7 V = 18.5 W⋅h",
+            description: "Battery capacity at a nominal voltage to watt-hours",
+        },
+    ];
+
+    run_tests(test_cases);
+}
+
+#[test]
+fn test_force_units() {
+    let test_cases = vec![
+        TestCase {
+            input: "10 kN to lbf",
+            expected_output: "[FORCE] 10 kN = 2248.09 lbf",
+            description: "Kilonewtons to pounds-force",
+        },
+        TestCase {
+            input: "1 kgf = N",
+            expected_output: "[FORCE] 1 kgf = 9.81 N",
+            description: "Kilograms-force to newtons",
+        },
+        TestCase {
+            input: "1 kip to kN",
+            expected_output: "[FORCE] 1 kip = 4.45 kN",
+            description: "Kips to kilonewtons",
+        },
+        TestCase {
+            input: "1 pdl to dyn",
+            expected_output: "[FORCE] 1 pdl = 13825.5 dyn",
+            description: "Poundals to dynes",
+        },
+        TestCase {
+            input: "1 kg * 1 m/s² to N",
+            expected_output: "[FORCE] 1 kg * 1 m/s² = 1 N",
+            description: "Mass times acceleration to newtons",
+        },
+    ];
+
+    run_tests(test_cases);
+}
+
+#[test]
+fn test_torque_units() {
+    let test_cases = vec![
+        TestCase {
+            input: "10 Nm to lbf⋅in",
+            expected_output: "[TORQUE] 10 N⋅m = 88.51 lbf⋅in",
+            description: "Newton-meters to pound-force inches",
+        },
+        TestCase {
+            input: "5 kgf⋅m to N*m",
+            expected_output: "[TORQUE] 5 kgf⋅m = 49.03 N⋅m",
+            description: "Kilogram-force meters to newton-meters",
+        },
+        TestCase {
+            input: "1 N⋅m to J",
+            expected_output: "[ Incompatible unit types: 'TORQUE' and 'ENERGY' ]",
+            description: "Torque to energy",
+        },
+    ];
+
+    run_tests(test_cases);
+}
+
+#[test]
+fn test_power_units() {
+    let test_cases = vec![
+        TestCase {
+            input: "2 kW to W",
+            expected_output: "[POWER] 2 kW = 2000 W",
+            description: "Kilowatts to watts",
+        },
+        TestCase {
+            input: "1 megawatt = kilowatts",
+            expected_output: "[POWER] 1 MW = 1000 kW",
+            description: "Megawatts to kilowatts by name",
+        },
+        TestCase {
+            input: "1 erg/s to nW",
+            expected_output: "[POWER] 1 erg/s = 100 nW",
+            description: "Ergs per second to nanowatts",
+        },
+        TestCase {
+            input: "30 dBm to W",
+            expected_output: "[POWER] 30 dBm = 1 W",
+            description: "Power level to watts",
+        },
+    ];
+
+    run_tests(test_cases);
+}
+
+#[test]
+fn test_speed_units() {
+    let test_cases = vec![
+        TestCase {
+            input: "100 km/h to mph",
+            expected_output: "[SPEED] 100 km/h = 62.14 mph",
+            description: "Kilometers per hour to miles per hour",
+        },
+        TestCase {
+            input: "20 knots = m/s",
+            expected_output: "[SPEED] 20 kn = 10.29 m/s",
+            description: "Knots to meters per second",
+        },
+        TestCase {
+            input: "2 mach to km/h",
+            expected_output: "[SPEED] 2 Ma = 2450.12 km/h",
+            description: "Mach (at sea level) to kilometers per hour",
+        },
+        TestCase {
+            input: "12 km/h to min/km",
+            expected_output: "[SPEED] 12 km/h = 5 min/km",
+            description: "Speed to pace",
+        },
+    ];
+
+    run_tests(test_cases);
+}
+
+#[test]
+fn test_astronomical_and_atomic_length_units() {
+    let test_cases = vec![
+        TestCase {
+            input: "1 au to km",
+            expected_output: "[LENGTH] 1 au = 149597870.7 km",
+            description: "Astronomical units to kilometers",
+        },
+        TestCase {
+            input: "1 pc = ly",
+            expected_output: "[LENGTH] 1 pc = 3.26 ly",
+            description: "Parsecs to light-years",
+        },
+        TestCase {
+            input: "1 Å to nm",
+            expected_output: "[LENGTH] 1 Å = 0.1 nm",
+            description: "Ångströms to nanometers",
+        },
+        TestCase {
+            input: "1000 fermi to pm",
+            expected_output: "[LENGTH] 1000 fm = 1 pm",
+            description: "Fermis to picometers",
+        },
+        TestCase {
+            input: "10 thou to mm",
+            expected_output: "[LENGTH] 10 mil = 0.25 mm",
+            description: "Thous to millimeters",
+        },
+        TestCase {
+            input: "1 pica to pt",
+            expected_output: "[LENGTH] 1 pica = 12 pt",
+            description: "Picas to points",
+        },
+        TestCase {
+            input: "2 fathoms to ft",
+            expected_output: "[LENGTH] 2 ftm = 12 ft",
+            description: "Fathoms to feet",
+        },
+    ];
+
+    run_tests(test_cases);
+}
+
 #[test]
 fn test_complex_length_units() {
     let test_cases = vec![
@@ -241,13 +477,13 @@ fn test_edge_cases_and_errors() {
         // very large numbers
         TestCase {
             input: "9999mi 9999yd 9999ft 9999in = km",
-            expected_output: "[LENGTH] 9999 mi 9999 yd 9999 ft 9999 in = 16104.24 km",
+            expected_output: "[LENGTH] 9999 mi 9999 yd 9999 ft 9999 in = 16104.28 km",
             description: "Very large mixed numbers",
         },
         // mismatched unit type
         TestCase {
-            input: "5ft 10parsecs = m",
-            expected_output: "[ Unit 'parsecs' not found in type 'LENGTH' ]",
+            input: "5ft 10smoots = m",
+            expected_output: "[ Unit 'smoots' not found in type 'LENGTH' ]",
             description: "Using an unknown unit",
         },
         // multiple mismatched unit types
@@ -0,0 +1,335 @@
+extern crate cu;
+
+#[cfg(test)]
+mod config_test {
+    use std::process::Command;
+
+    use cu::{ConvertError, UnitRegistry};
+
+    #[test]
+    fn test_load_units() {
+        let mut registry = UnitRegistry::default();
+        let conflicts = registry
+            .load_units(
+                r#"
+            [[types]]
+            name = "Throughput"
+            dimension = "B/s"
+
+            [[units]]
+            type = "LENGTH"
+            name = "Rack Unit"
+            abbr = "U"
+            aliases = ["rack unit", "rack units"]
+            ratio = 0.04445
+
+            [[units]]
+            type = "story points"
+            name = "Story Point"
+            abbr = "sp"
+            ratio = 1
+
+            [[units]]
+            type = "STORY POINTS"
+            name = "Sprint"
+            abbr = "sprint"
+            ratios = [["Small team", 20], ["Large team", 40]]
+
+            [[units]]
+            type = "THROUGHPUT"
+            name = "Compute Unit"
+            abbr = "CU"
+            ratio = 8e6
+
+            [[units]]
+            type = "PRESSURE"
+            name = "Test Gauge Pressure"
+            abbr = "tpg"
+            ratio = 1000
+            offset = 101325
+            "#,
+            )
+            .unwrap();
+        assert!(conflicts.is_empty());
+
+        // a unit of a built-in unit type
+        let conversion = registry.convert("42 U = m").unwrap();
+        assert_eq!(conversion.unit_type, "LENGTH");
+        assert_eq!(conversion.lines(), vec!["[LENGTH] 42 U = 1.87 m"]);
+        assert_eq!(
+            registry.convert("2 rack units = cm").unwrap().lines(),
+            vec!["[LENGTH] 2 U = 8.89 cm"]
+        );
+
+        // a new unit type (with a new base dimension) and ratio variants
+        assert_eq!(
+            registry.convert("60 sp = sprint").unwrap().lines(),
+            vec![
+                "[STORY POINTS] 60 sp = 3 sprint (Small team)",
+                "[STORY POINTS] 60 sp = 1.5 sprint (Large team)",
+            ]
+        );
+        assert_eq!(
+            registry.convert("1 U = sp").err(),
+            Some(ConvertError::DimensionMismatch {
+                left: "LENGTH".to_string(),
+                right: "STORY POINTS".to_string(),
+            })
+        );
+
+        // a new unit type with the dimension of a built-in unit
+        assert_eq!(
+            registry.convert("2 CU = MB/s").unwrap().lines(),
+            vec!["[THROUGHPUT] 2 CU = 2 MB/s"]
+        );
+        assert_eq!(
+            registry.convert("2 CU = MB/min").unwrap().lines(),
+            vec!["[THROUGHPUT] 2 CU = 120 MB/min"]
+        );
+        assert_eq!(
+            registry.convert("16 Mbps = CU").unwrap().lines(),
+            vec!["[THROUGHPUT] 16 Mbps = 2 CU"]
+        );
+        assert_eq!(
+            registry.convert("1 CU + 1 MB/s = MB/s").unwrap().lines(),
+            vec!["[THROUGHPUT] 1 CU + 1 MB/s = 2 MB/s"]
+        );
+
+        // a unit with an offset
+        assert_eq!(
+            registry.convert("1 atm = tpg").unwrap().lines(),
+            vec!["[PRESSURE] 1 atm = 0 tpg"]
+        );
+        assert_eq!(
+            registry.convert("100 tpg = kPa").unwrap().lines(),
+            vec!["[PRESSURE] 100 tpg = 201.33 kPa"]
+        );
+        assert_eq!(
+            registry.convert("1 tpg 1 kPa = kPa").err(),
+            Some(ConvertError::OffsetUnitArithmetic("tpg".to_string()))
+        );
+        assert_eq!(
+            registry.convert("2 * 1 tpg = kPa").err(),
+            Some(ConvertError::OffsetUnitArithmetic("tpg".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_load_units_conflicts() {
+        let mut registry = UnitRegistry::default();
+        let conflicts = registry
+            .load_units(
+                r#"
+            [[units]]
+            type = "MASS"
+            name = "Pallet"
+            abbr = "plt"
+            aliases = ["KG"]
+            ratio = 1000
+
+            [[units]]
+            type = "MASS"
+            name = "Crate"
+            abbr = "crt"
+            ratio = 25
+
+            [[units]]
+            type = "VOLUME"
+            name = "Crate Volume"
+            abbr = "CRT"
+            ratio = 0.05
+            "#,
+            )
+            .unwrap();
+        assert_eq!(
+            conflicts,
+            vec![
+                ConvertError::UnitConflict {
+                    unit: "Pallet".to_string(),
+                    symbol: "KG".to_string(),
+                    existing: "Kilogram (MASS)".to_string(),
+                },
+                ConvertError::UnitConflict {
+                    unit: "Crate Volume".to_string(),
+                    symbol: "CRT".to_string(),
+                    existing: "Crate (MASS)".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            conflicts[0].to_string(),
+            "[ Unit conflict: 'KG' of Pallet is already used by Kilogram (MASS), the unit is skipped ]"
+        );
+
+        // the conflicting units are skipped, the other ones are loaded
+        assert_eq!(
+            registry.convert("1 kg = plt").err(),
+            Some(ConvertError::UnknownUnit("plt".to_string()))
+        );
+        assert_eq!(
+            registry.convert("4 crt = kg").unwrap().lines(),
+            vec!["[MASS] 4 crt = 100 kg"]
+        );
+    }
+
+    #[test]
+    fn test_load_units_custom_dimensions() {
+        let types = |names: &[&str]| {
+            names
+                .iter()
+                .map(|name| {
+                    format!(
+                        "[[types]]\nname = \"{0}\"\n\n\
+                        [[units]]\ntype = \"{0}\"\nname = \"{0} Unit\"\nabbr = \"{0}_unit\"\nratio = 1\n",
+                        name
+                    )
+                })
+                .collect::<String>()
+        };
+        // the custom base dimensions are per registry
+        let names = ["A1", "A2", "A3", "A4", "A5", "A6", "A7", "A8"];
+        for _ in 0..2 {
+            let mut registry = UnitRegistry::default();
+            assert_eq!(registry.load_units(&types(&names)), Ok(vec![]));
+            assert_eq!(
+                registry.load_units(&types(&["A9"])).err(),
+                Some(ConvertError::InvalidUnitsFile(
+                    "too many new base unit types (the unit type A9 needs a dimension)".to_string()
+                ))
+            );
+        }
+
+        // an invalid file adds no custom base dimension (nor any unit)
+        let mut registry = UnitRegistry::default();
+        assert_eq!(registry.load_units(&types(&names[..7])), Ok(vec![]));
+        assert_eq!(
+            registry
+                .load_units(
+                    "[[units]]\ntype = \"B1\"\nname = \"B1 Unit\"\nabbr = \"B1_unit\"\nratio = 1\n\n\
+                    [[units]]\ntype = \"LENGTH\"\nname = \"B2 Unit\"\nabbr = \"B2_unit\"\n"
+                )
+                .err(),
+            Some(ConvertError::InvalidUnitsFile(
+                "the unit B2 Unit has no ratio".to_string()
+            ))
+        );
+        assert_eq!(
+            registry.convert("1 B1_unit = B1_unit").err(),
+            Some(ConvertError::UnknownUnit("B1_unit".to_string()))
+        );
+        assert_eq!(registry.load_units(&types(&["A8"])), Ok(vec![]));
+        assert_eq!(
+            registry.convert("2 A8_unit = A8_unit").unwrap().lines(),
+            vec!["[A8] 2 A8_unit = 2 A8_unit"]
+        );
+        // and are described by the unit types of the registry
+        let mut registry = UnitRegistry::default();
+        registry
+            .load_units("[[units]]\ntype = \"STORY POINTS\"\nname = \"Story Point\"\nabbr = \"sp\"\nratio = 1\n")
+            .unwrap();
+        assert_eq!(
+            registry.convert("1 sp / 1 s = ft").err(),
+            Some(ConvertError::DimensionMismatch {
+                left: "T⁻¹·[STORY POINTS]".to_string(),
+                right: "LENGTH".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_load_units_errors() {
+        let mut registry = UnitRegistry::default();
+        assert_eq!(
+            registry
+                .load_units("[[units]]\ntype = \"LENGTH\"\nname = \"Test Span\"\nabbr = \"tsp1\"\n")
+                .err(),
+            Some(ConvertError::InvalidUnitsFile(
+                "the unit Test Span has no ratio".to_string()
+            ))
+        );
+        assert_eq!(
+            registry.load_units(
+                "[[units]]\ntype = \"LENGTH\"\nname = \"Test Span\"\nabbr = \"tsp1\"\nratio = -1\n"
+            )
+            .err(),
+            Some(ConvertError::InvalidUnitsFile(
+                "the unit Test Span has a ratio that is not a positive number".to_string()
+            ))
+        );
+        assert_eq!(
+            registry
+                .load_units("[[types]]\nname = \"TEST\"\ndimension = \"foo/s\"\n")
+                .err(),
+            Some(ConvertError::InvalidUnitsFile(
+                "unknown unit 'foo/s' in the dimension of TEST".to_string()
+            ))
+        );
+        assert_eq!(
+            registry.load_units("[[types]]\nname = \"length\"\n").err(),
+            Some(ConvertError::InvalidUnitsFile(
+                "the unit type LENGTH already exists".to_string()
+            ))
+        );
+        // a new unit type without units
+        assert_eq!(
+            registry
+                .load_units("[[types]]\nname = \"throughput\"\ndimension = \"B/s\"\n")
+                .err(),
+            Some(ConvertError::InvalidUnitsFile(
+                "the unit type THROUGHPUT has no units".to_string()
+            ))
+        );
+        assert!(registry
+            .load_units("[[units]]\nname = \"Test Span\"\nabbr = 1\n")
+            .err()
+            .unwrap()
+            .to_string()
+            .starts_with("[ Invalid units file: line 3: "));
+    }
+
+    #[test]
+    fn test_units_file_option() {
+        let path = std::env::temp_dir().join(format!("cu_units_test_{}.toml", std::process::id()));
+        std::fs::write(
+            &path,
+            "[[units]]\ntype = \"LENGTH\"\nname = \"Rack Unit\"\nabbr = \"U\"\nratio = 0.04445\n\n\
+            [[units]]\ntype = \"TIME\"\nname = \"Mini\"\nabbr = \"min\"\nratio = 60\n",
+        )
+        .unwrap();
+        let output = Command::new("cargo")
+            .args(["run", "-q", "--", "--units-file"])
+            .arg(&path)
+            .args(["42", "U", "=", "m"])
+            .output()
+            .expect("Failed to execute command");
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "[LENGTH] 42 U = 1.87 m\n"
+        );
+        assert_eq!(
+            String::from_utf8_lossy(&output.stderr),
+            "[ Unit conflict: 'min' of Mini is already used by Minute (TIME), the unit is skipped ]\n"
+        );
+        assert!(output.status.success());
+
+        // a missing units file
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-q",
+                "--",
+                "--units-file=missing.toml",
+                "1",
+                "m",
+                "=",
+                "ft",
+            ])
+            .output()
+            .expect("Failed to execute command");
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "");
+        assert!(String::from_utf8_lossy(&output.stderr)
+            .starts_with("[ Invalid units file: can't read missing.toml: "));
+        assert!(!output.status.success());
+    }
+}
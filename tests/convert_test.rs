@@ -0,0 +1,688 @@
+extern crate cu;
+
+#[cfg(test)]
+mod convert_test {
+    use cu::expr::Variables;
+    use cu::{convert, convert_with, ConvertError, UnitRegistry};
+
+    #[test]
+    fn test_convert_single_unit() {
+        let conversion = convert("5ft 10in to m").unwrap();
+        assert_eq!(conversion.unit_type, "LENGTH");
+        assert_eq!(
+            conversion.sources,
+            vec![(5.0, "ft".to_string()), (10.0, "in".to_string())]
+        );
+        assert_eq!(conversion.target.abbr, "m");
+        assert_eq!(conversion.precision, 2);
+        assert_eq!(conversion.results.len(), 1);
+        assert!((conversion.results[0].value - 1.778).abs() < 1e-9);
+        assert_eq!(conversion.results[0].source_variant, None);
+        assert_eq!(conversion.results[0].target_variant, None);
+        assert_eq!(
+            conversion.lines(),
+            vec!["[LENGTH] 5 ft 10 in = 1.78 m".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_convert_variants() {
+        let conversion = convert("1gal = qt:1").unwrap();
+        assert_eq!(conversion.unit_type, "VOLUME");
+        assert_eq!(conversion.precision, 1);
+        let variants: Vec<(Option<&str>, Option<&str>)> = conversion
+            .results
+            .iter()
+            .map(|r| (r.source_variant.as_deref(), r.target_variant.as_deref()))
+            .collect();
+        assert_eq!(
+            variants,
+            vec![
+                (Some("Imperial"), Some("Imperial")),
+                (Some("Imperial"), Some("US liquid")),
+                (Some("US liquid"), Some("Imperial")),
+                (Some("US liquid"), Some("US liquid")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_convert_expression() {
+        let conversion = convert("(3 ft + 4 in) * 2 to cm").unwrap();
+        assert_eq!(conversion.unit_type, "LENGTH");
+        assert_eq!(conversion.source, "(3 ft + 4 in) * 2");
+        assert_eq!(
+            conversion.sources,
+            vec![(3.0, "ft".to_string()), (4.0, "in".to_string())]
+        );
+        assert!((conversion.results[0].value - 203.2).abs() < 1e-9);
+
+        assert_eq!(
+            convert("1 m * 2 m = m").err(),
+            Some(ConvertError::AmbiguousUnitMismatch {
+                left: "AREA".to_string(),
+                unit: "m".to_string(),
+                candidates: vec!["Meter (LENGTH)".to_string(), "Minute (TIME)".to_string()],
+            })
+        );
+        assert_eq!(
+            convert("(1 m = m").err(),
+            Some(ConvertError::InvalidExpression(
+                "missing closing parenthesis".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_convert_temperature() {
+        let conversion = convert("100°C = °F").unwrap();
+        assert_eq!(conversion.unit_type, "TEMPERATURE");
+        assert_eq!(conversion.results.len(), 1);
+        assert!((conversion.results[0].value - 212.0).abs() < 1e-9);
+
+        // any affine unit converts into any other one
+        let values = |input: &str| convert(input).unwrap().results[0].value;
+        assert!((values("0 K = °C") + 273.15).abs() < 1e-9);
+        assert!((values("491.67 °R = °F") - 32.0).abs() < 1e-9);
+        assert!((values("80 °Ré = °C") - 100.0).abs() < 1e-9);
+        assert!((values("60 °Rø = °F") - 212.0).abs() < 1e-9);
+        assert!((values("0 °De = K") - 373.15).abs() < 1e-9);
+        assert!((values("33 °N = °Ré") - 80.0).abs() < 1e-9);
+        assert!((values("0 K = K")).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_convert_pace() {
+        let conversion = convert("5:30 min/km to mph").unwrap();
+        assert_eq!(conversion.unit_type, "SPEED");
+        assert_eq!(conversion.lines(), vec!["[SPEED] 5.5 min/km = 6.78 mph"]);
+        assert_eq!(
+            convert("10 mph to min/km").unwrap().lines(),
+            vec!["[SPEED] 10 mph = 3.73 min/km"]
+        );
+        assert_eq!(
+            convert("8 min/mi = min/km").unwrap().lines(),
+            vec!["[SPEED] 8 min/mi = 4.97 min/km"]
+        );
+
+        // paces can't be summed up or scaled
+        assert_eq!(
+            convert("5 min/km 1 min/km to mph").err(),
+            Some(ConvertError::NonLinearUnitArithmetic("min/km".to_string()))
+        );
+        assert_eq!(
+            convert("2 * 5 min/km to mph").err(),
+            Some(ConvertError::NonLinearUnitArithmetic("min/km".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_convert_astronomical_lengths() {
+        // the exact defining values
+        let meters = |input: &str| convert(input).unwrap().results[0].value;
+        assert_eq!(meters("1 au to m"), 149597870700.0);
+        assert_eq!(meters("1 ly to m"), 9460730472580800.0);
+        assert_eq!(meters("1 ls to m"), 299792458.0);
+        assert!((meters("1 pc to au") - 648000.0 / std::f64::consts::PI).abs() < 1e-6);
+        assert_eq!(
+            convert("1 kpc to ly").unwrap().lines(),
+            vec!["[LENGTH] 1 kpc = 3261.56 ly"]
+        );
+    }
+
+    #[test]
+    fn test_convert_survey_lengths() {
+        // the US survey foot is 2 ppm longer than the international foot
+        assert_eq!(
+            convert("1 ftUS to ft:*").unwrap().lines(),
+            vec!["[LENGTH] 1 ftUS = 1.000002000004 ft"]
+        );
+        let conversion = convert("1 fur to m:4").unwrap();
+        assert_eq!(
+            conversion.lines(),
+            vec![
+                "[LENGTH] 1 fur (International) = 201.168 m",
+                "[LENGTH] 1 fur (US survey) = 201.1684 m",
+            ]
+        );
+        assert_eq!(
+            convert("1 cbl to m").unwrap().lines(),
+            vec![
+                "[LENGTH] 1 cbl (International) = 185.2 m",
+                "[LENGTH] 1 cbl (Imperial) = 185.32 m",
+                "[LENGTH] 1 cbl (US) = 219.46 m",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_convert_data_rate() {
+        let conversion = convert("4 TB / 1 Gbps to hr").unwrap();
+        assert_eq!(conversion.unit_type, "TIME");
+        assert!((conversion.results[0].value - 32000.0 / 3600.0).abs() < 1e-9);
+        assert_eq!(
+            convert("10 MB / 1 s to Mbps").unwrap().lines(),
+            vec!["[DATA RATE] 10 MB / 1 s = 80 Mbps"]
+        );
+        // the bit and byte symbols are case-sensitive
+        assert_eq!(
+            convert("1 Bps to bps").unwrap().lines(),
+            vec!["[DATA RATE] 1 B/s = 8 bps"]
+        );
+        assert_eq!(
+            convert("8 Mb/s to MBps").unwrap().lines(),
+            vec!["[DATA RATE] 8 Mbps = 1 MB/s"]
+        );
+    }
+
+    #[test]
+    fn test_convert_battery_capacity() {
+        let conversion = convert("5000 mAh @ 3.7 V to W⋅h").unwrap();
+        assert_eq!(conversion.unit_type, "ENERGY");
+        assert_eq!(
+            conversion.lines(),
+            vec!["[ENERGY] 5000 mAh @ 3.7 V = 18.5 W⋅h"]
+        );
+        // the factor divides the source if the target needs it
+        assert_eq!(
+            convert("18.5 Wh @ 3.7 V to mAh").unwrap().lines(),
+            vec!["[ELECTRIC CHARGE] 18.5 W⋅h @ 3.7 V = 5000 mAh"]
+        );
+        // the factor is not applied if the source already has the dimension of the target
+        assert_eq!(
+            convert("5000 mAh @ 3.7 V to Ah").unwrap().lines(),
+            vec!["[ELECTRIC CHARGE] 5000 mAh @ 3.7 V = 5 Ah"]
+        );
+        assert_eq!(
+            convert("5000 mAh @ 3.7 m to W⋅h").err(),
+            Some(ConvertError::DimensionMismatch {
+                left: "L⁻¹·T·I".to_string(),
+                right: "ENERGY".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_convert_torque() {
+        let conversion = convert("100 N⋅m to lbf⋅ft").unwrap();
+        assert_eq!(conversion.unit_type, "TORQUE");
+        assert_eq!(conversion.lines(), vec!["[TORQUE] 100 N⋅m = 73.76 lbf⋅ft"]);
+        // a product of a force and a length converts into a torque or an energy
+        assert_eq!(
+            convert("5 lbf * 2 ft to lbf⋅ft").unwrap().lines(),
+            vec!["[TORQUE] 5 lbf * 2 ft = 10 lbf⋅ft"]
+        );
+        assert_eq!(
+            convert("2 N * 3 m to J").unwrap().lines(),
+            vec!["[ENERGY] 2 N * 3 m = 6 J"]
+        );
+
+        // torques and energies share a dimension but don't convert into each other
+        assert_eq!(
+            convert("1 N⋅m to J").err(),
+            Some(ConvertError::DimensionMismatch {
+                left: "TORQUE".to_string(),
+                right: "ENERGY".to_string(),
+            })
+        );
+        assert_eq!(
+            convert("1 ft⋅lbf = lbf⋅ft").err(),
+            Some(ConvertError::DimensionMismatch {
+                left: "ENERGY".to_string(),
+                right: "TORQUE".to_string(),
+            })
+        );
+        assert_eq!(
+            convert("1 N⋅m + 1 J to J").err(),
+            Some(ConvertError::DimensionMismatch {
+                left: "TORQUE".to_string(),
+                right: "ENERGY".to_string(),
+            })
+        );
+
+        // other products of the dimension are energies
+        assert_eq!(
+            convert("1 kW*h to N*m").err(),
+            Some(ConvertError::DimensionMismatch {
+                left: "ENERGY".to_string(),
+                right: "TORQUE".to_string(),
+            })
+        );
+        assert_eq!(
+            convert("1 W*s to lbf*ft").err(),
+            Some(ConvertError::DimensionMismatch {
+                left: "ENERGY".to_string(),
+                right: "TORQUE".to_string(),
+            })
+        );
+        assert_eq!(
+            convert("1 kW * 1 h to N⋅m").err(),
+            Some(ConvertError::DimensionMismatch {
+                left: "ENERGY".to_string(),
+                right: "TORQUE".to_string(),
+            })
+        );
+        assert_eq!(
+            convert("1 kW*h to J").unwrap().lines(),
+            vec!["[ENERGY] 1 kW⋅hr = 3600000 J"]
+        );
+    }
+
+    #[test]
+    fn test_convert_power() {
+        let conversion = convert("1 hp to W").unwrap();
+        assert_eq!(conversion.unit_type, "POWER");
+        assert_eq!(
+            conversion.lines(),
+            vec![
+                "[POWER] 1 hp (Mechanical) = 745.7 W",
+                "[POWER] 1 hp (Metric) = 735.5 W",
+                "[POWER] 1 hp (Electrical) = 746 W",
+            ]
+        );
+        assert_eq!(
+            convert("1 TR to BTU/h").unwrap().lines(),
+            vec!["[POWER] 1 TR = 12000 BTU/h"]
+        );
+        assert_eq!(
+            convert("3 kW⋅h / 2 h to W").unwrap().lines(),
+            vec!["[POWER] 3 kW⋅h / 2 hr = 1500 W"]
+        );
+    }
+
+    #[test]
+    fn test_convert_power_levels() {
+        assert_eq!(
+            convert("100 W to dBm").unwrap().lines(),
+            vec!["[POWER] 100 W = 50 dBm"]
+        );
+        assert_eq!(
+            convert("-10 dBm to mW").unwrap().lines(),
+            vec!["[POWER] -10 dBm = 0.1 mW"]
+        );
+        assert_eq!(
+            convert("0 dBW = dBm").unwrap().lines(),
+            vec!["[POWER] 0 dBW = 30 dBm"]
+        );
+
+        // power levels can't be summed up or scaled
+        assert_eq!(
+            convert("10 dBm + 10 dBm to mW").err(),
+            Some(ConvertError::NonLinearUnitArithmetic("dBm".to_string()))
+        );
+        assert_eq!(
+            convert("2 * 3 dBW to W").err(),
+            Some(ConvertError::NonLinearUnitArithmetic("dBW".to_string()))
+        );
+
+        // only a positive power has a level
+        assert_eq!(
+            convert("0 W to dBm").err(),
+            Some(ConvertError::ValueOutOfRange {
+                value: "0 W".to_string(),
+                unit: "dBm".to_string(),
+            })
+        );
+        assert_eq!(
+            convert("-5 W to dBm").err(),
+            Some(ConvertError::ValueOutOfRange {
+                value: "-5 W".to_string(),
+                unit: "dBm".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_convert_fuel_economy() {
+        let conversion = convert("30 mpg to L/100km").unwrap();
+        assert_eq!(conversion.unit_type, "FUEL ECONOMY");
+        assert_eq!(
+            conversion.lines(),
+            vec![
+                "[FUEL ECONOMY] 30 mpg (Imperial) = 9.42 L/100km",
+                "[FUEL ECONOMY] 30 mpg (US) = 7.84 L/100km",
+            ]
+        );
+        // L/100km = 235.215 / mpg (US)
+        let conversion = convert("1 L/100km = mpg").unwrap();
+        assert!((conversion.results[1].value - 235.215).abs() < 1e-3);
+        assert_eq!(
+            convert("15 km/L = L/100 km").unwrap().lines(),
+            vec!["[FUEL ECONOMY] 15 km/L = 6.67 L/100km"]
+        );
+        // a fuel economy of 0 has no fuel consumption
+        assert_eq!(
+            convert("0 mpg to L/100km").err(),
+            Some(ConvertError::ValueOutOfRange {
+                value: "0 mpg".to_string(),
+                unit: "L/100km".to_string(),
+            })
+        );
+        assert_eq!(
+            convert("0 mpg to L/100km").err().unwrap().to_string(),
+            "[ Value out of range: 0 mpg has no value in L/100km ]"
+        );
+    }
+
+    #[test]
+    fn test_convert_reciprocal() {
+        // a quantity of the inverse dimension of the target unit is converted through its reciprocal
+        assert_eq!(
+            convert("5 L / 100 km to mpg").unwrap().lines(),
+            vec![
+                "[FUEL ECONOMY] 5 l / 100 km = 56.5 mpg (Imperial)",
+                "[FUEL ECONOMY] 5 l / 100 km = 47.04 mpg (US)",
+            ]
+        );
+        assert_eq!(
+            convert("300 s/km to mph").unwrap().lines(),
+            vec!["[SPEED] 300 s/km = 7.46 mph"]
+        );
+        assert_eq!(
+            convert("4 Hz to ms").unwrap().lines(),
+            vec!["[TIME] 4 Hz = 250 ms"]
+        );
+        // 0 has no reciprocal
+        assert_eq!(
+            convert("0 mph to s/m").err(),
+            Some(ConvertError::ValueOutOfRange {
+                value: "0 mph".to_string(),
+                unit: "s/m".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_convert_temperature_differences() {
+        // differences only have a ratio
+        assert_eq!(
+            convert("10 Δ°C to Δ°F").unwrap().lines(),
+            vec!["[TEMPERATURE DIFFERENCE] 10 Δ°C = 18 Δ°F"]
+        );
+        assert_eq!(
+            convert("10 delta C to delta F").unwrap().lines(),
+            vec!["[TEMPERATURE DIFFERENCE] 10 Δ°C = 18 Δ°F"]
+        );
+
+        // an absolute temperature plus or minus a difference is an absolute temperature
+        assert_eq!(
+            convert("20 °C + 5 Δ°C to °C").unwrap().lines(),
+            vec!["[TEMPERATURE] 20 °C + 5 Δ°C = 25 °C"]
+        );
+        assert_eq!(
+            convert("5 Δ°F + 32 °F to °C").unwrap().lines(),
+            vec!["[TEMPERATURE] 5 Δ°F + 32 °F = 2.78 °C"]
+        );
+        assert_eq!(
+            convert("20 °C 5 Δ°C to °C").unwrap().lines(),
+            vec!["[TEMPERATURE] 20 °C 5 Δ°C = 25 °C"]
+        );
+
+        // the difference of two absolute temperatures is a difference
+        assert_eq!(
+            convert("30 °C - 20 °C to Δ°F").unwrap().lines(),
+            vec!["[TEMPERATURE DIFFERENCE] 30 °C - 20 °C = 18 Δ°F"]
+        );
+
+        // absolute temperatures and differences don't convert into each other
+        assert_eq!(
+            convert("10 Δ°C to °F").err(),
+            Some(ConvertError::DimensionMismatch {
+                left: "TEMPERATURE DIFFERENCE".to_string(),
+                right: "TEMPERATURE".to_string(),
+            })
+        );
+        let absolute_value_arithmetic = Some(ConvertError::AbsoluteValueArithmetic {
+            unit_type: "TEMPERATURE".to_string(),
+            difference_type: "TEMPERATURE DIFFERENCE".to_string(),
+        });
+        assert_eq!(
+            convert("20 °C + 5 °C to °C").err(),
+            absolute_value_arithmetic
+        );
+        assert_eq!(
+            convert("5 Δ°C - 20 °C to °C").err(),
+            absolute_value_arithmetic
+        );
+        assert_eq!(
+            convert("(20 °C + 5 Δ°C) * 2 to °C").err(),
+            Some(ConvertError::OffsetUnitArithmetic("°C".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_convert_affine_sources() {
+        // an absolute temperature and differences in a list of values
+        assert_eq!(
+            convert("300 K 5 Δ°C to °C").unwrap().lines(),
+            vec!["[TEMPERATURE] 300 K 5 Δ°C = 31.85 °C"]
+        );
+        assert_eq!(
+            convert("300 K 20 °C to °C").err(),
+            Some(ConvertError::AbsoluteValueArithmetic {
+                unit_type: "TEMPERATURE".to_string(),
+                difference_type: "TEMPERATURE DIFFERENCE".to_string(),
+            })
+        );
+
+        // affine units in composed units are differences
+        assert_eq!(
+            convert("20 °C/min to K/s").unwrap().lines(),
+            vec!["[T⁻¹·Θ] 20 °C/min = 0.33 K/s"]
+        );
+        assert_eq!(
+            convert("90 J/°F to J/K").unwrap().lines(),
+            vec!["[L²·M·T⁻²·Θ⁻¹] 90 J/°F = 162 J/K"]
+        );
+    }
+
+    #[test]
+    fn test_convert_precision_warnings() {
+        let conversion = convert("1m to cm:20").unwrap();
+        assert_eq!(conversion.precision, 14);
+        assert_eq!(
+            conversion.warnings,
+            vec![ConvertError::PrecisionTooHigh("20".to_string())]
+        );
+
+        let conversion = convert("1m to cm:x").unwrap();
+        assert_eq!(conversion.precision, 2);
+        assert_eq!(
+            conversion.warnings,
+            vec![ConvertError::InvalidPrecision("x".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_convert_errors() {
+        assert_eq!(
+            convert("5ft 10in").err(),
+            Some(ConvertError::MissingSeparator)
+        );
+        assert_eq!(
+            convert("ft = m").err(),
+            Some(ConvertError::MissingSourceValues)
+        );
+        assert_eq!(
+            convert("5ft = unknown").err(),
+            Some(ConvertError::UnknownUnit("unknown".to_string()))
+        );
+        assert_eq!(
+            convert("5ft 10kg = m").err(),
+            Some(ConvertError::UnitTypeMismatch {
+                units: vec!["kg".to_string()],
+                unit_type: "LENGTH".to_string(),
+            })
+        );
+        assert_eq!(
+            convert("1K 2°C = °F").err(),
+            Some(ConvertError::AbsoluteValueArithmetic {
+                unit_type: "TEMPERATURE".to_string(),
+                difference_type: "TEMPERATURE DIFFERENCE".to_string(),
+            })
+        );
+        // exponents out of range (rather than an overflow)
+        assert_eq!(
+            convert("1 J^100 to m").err(),
+            Some(ConvertError::ExponentOutOfRange("J^100".to_string()))
+        );
+        assert_eq!(
+            convert("1 m^100 * 1 m^100 to m").err(),
+            Some(ConvertError::ExponentOutOfRange("m^100".to_string()))
+        );
+        // values that aren't finite (rather than "inf m")
+        assert_eq!(
+            convert("1e400 m to ft").err(),
+            Some(ConvertError::NonFiniteValue("1e400".to_string()))
+        );
+        assert_eq!(
+            convert("1e100000000 m to ft").err(),
+            Some(ConvertError::NonFiniteValue("1e100000000".to_string()))
+        );
+        assert_eq!(
+            convert("1/0 m to ft").err(),
+            Some(ConvertError::NonFiniteValue("1/0".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_convert_ambiguous_units() {
+        // the target unit is resolved by the source unit
+        let conversion = convert("300 s = m").unwrap();
+        assert_eq!(conversion.unit_type, "TIME");
+        assert_eq!(conversion.target.name, "Minute");
+        assert!((conversion.results[0].value - 5.0).abs() < 1e-9);
+
+        // the source unit is resolved by the target unit
+        let conversion = convert("2 d = hr").unwrap();
+        assert_eq!(conversion.unit_type, "TIME");
+        assert_eq!(conversion.sources, vec![(2.0, "d".to_string())]);
+
+        // both units are ambiguous but only one unit type fits both
+        let conversion = convert("100 c = f").unwrap();
+        assert_eq!(conversion.unit_type, "TEMPERATURE");
+        let conversion = convert("100 f = c").unwrap();
+        assert_eq!(conversion.unit_type, "TEMPERATURE");
+        assert_eq!(conversion.target.name, "Celsius");
+
+        // both units are ambiguous and more than one unit type fits both:
+        // the unit type of the source on its own is kept
+        let conversion = convert("1.5 m to m").unwrap();
+        assert_eq!(conversion.unit_type, "LENGTH");
+        assert_eq!(conversion.target.name, "Meter");
+        assert!((conversion.results[0].value - 1.5).abs() < 1e-9);
+        let conversion = convert("5 m = m").unwrap();
+        assert_eq!(conversion.unit_type, "LENGTH");
+        assert_eq!(
+            ConvertError::AmbiguousUnit {
+                unit: "m".to_string(),
+                candidates: vec!["Meter (LENGTH)".to_string(), "Minute (TIME)".to_string()],
+            }
+            .to_string(),
+            "[ Ambiguous unit 'm': it can be Meter (LENGTH) or Minute (TIME) ]"
+        );
+
+        // an identity conversion keeps the unit type of the source ("d" is a day rather than a degree)
+        let conversion = convert("1 d to d").unwrap();
+        assert_eq!(conversion.unit_type, "TIME");
+        assert_eq!(conversion.target.name, "Day");
+        // the best match of the target unit is kept when the source fits several candidates
+        let conversion = convert("2 rad * 3 m to m").unwrap();
+        assert_eq!(conversion.unit_type, "LENGTH");
+        assert!((conversion.results[0].value - 6.0).abs() < 1e-9);
+        // and no unit is guessed when the matches are as good as each other
+        assert!(matches!(
+            convert("1 c to c").err(),
+            Some(ConvertError::AmbiguousUnit { .. })
+        ));
+
+        // no unit type fits: every candidate is reported
+        let candidates = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+        assert_eq!(
+            convert("5 kg = m").err(),
+            Some(ConvertError::AmbiguousUnitMismatch {
+                left: "MASS".to_string(),
+                unit: "m".to_string(),
+                candidates: candidates(&["Meter (LENGTH)", "Minute (TIME)"]),
+            })
+        );
+        let error = convert("3 d to f").err().unwrap();
+        assert_eq!(
+            error,
+            ConvertError::AmbiguousUnitMismatch {
+                left: "TIME".to_string(),
+                unit: "f".to_string(),
+                candidates: candidates(&[
+                    "Farad (ELECTRIC CAPACITANCE)",
+                    "Fahrenheit (TEMPERATURE)"
+                ]),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "[ Incompatible unit types: 'TIME' and 'f', \
+             which can be Farad (ELECTRIC CAPACITANCE) or Fahrenheit (TEMPERATURE) ]"
+        );
+    }
+
+    #[test]
+    fn test_convert_ambiguous_units_in_compound_units() {
+        // "d" is the abbreviation of a day rather than an alias of a degree
+        let conversion = convert("10 km/d to m/s").unwrap();
+        assert_eq!(conversion.unit_type, "SPEED");
+        assert!((conversion.results[0].value - 10000.0 / 86400.0).abs() < 1e-9);
+        let conversion = convert("1 m/d to m/s").unwrap();
+        assert_eq!(conversion.unit_type, "SPEED");
+        let conversion = convert("1 TB/d to Mbps").unwrap();
+        assert_eq!(conversion.unit_type, "DATA RATE");
+        let conversion = convert("2 kW * 3 d to kW⋅h").unwrap();
+        assert_eq!(conversion.unit_type, "ENERGY");
+        assert!((conversion.results[0].value - 144.0).abs() < 1e-9);
+        assert!(matches!(
+            convert("10 km / 1 d to m").err(),
+            Some(ConvertError::AmbiguousUnitMismatch { .. })
+        ));
+
+        // a compound output unit round-trips
+        let conversion = convert("5 kW⋅h/d to W").unwrap();
+        assert_eq!(conversion.unit_type, "POWER");
+        assert!((conversion.results[0].value - 5000.0 / 24.0).abs() < 1e-9);
+        let conversion = convert("208.33 W to kW⋅h/d").unwrap();
+        assert_eq!(conversion.target.abbr, "kW⋅h/d");
+        assert!((conversion.results[0].value - 5.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_convert_with_variables() {
+        let mut variables = Variables::new();
+        variables.insert(
+            "x".to_string(),
+            UnitRegistry::default()
+                .evaluate("3 ft 4 in", None, &Variables::new())
+                .unwrap()
+                .quantity,
+        );
+
+        let conversion = convert_with("x * 2 to cm", &variables).unwrap();
+        assert_eq!(conversion.unit_type, "LENGTH");
+        assert_eq!(conversion.source, "x * 2");
+        assert!((conversion.results[0].value - 203.2).abs() < 1e-9);
+
+        // the converted quantity can be stored as a variable again
+        let quantity = conversion.quantity;
+        assert_eq!(quantity.unit_type.as_deref(), Some("LENGTH"));
+        variables.insert("ans".to_string(), quantity);
+        let conversion = convert_with("ans = in", &variables).unwrap();
+        assert!((conversion.results[0].value - 80.0).abs() < 1e-9);
+
+        // a temperature is stored in kelvins
+        let quantity = convert("100°C = °F").unwrap().quantity;
+        assert!((quantity.values[0] - 373.15).abs() < 1e-9);
+
+        // unknown variables
+        assert_eq!(
+            convert_with("y to cm", &variables).err(),
+            Some(ConvertError::MissingSourceValues)
+        );
+    }
+}
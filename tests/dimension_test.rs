@@ -0,0 +1,146 @@
+extern crate cu;
+
+#[cfg(test)]
+mod dimension_test {
+    use cu::{dimension::Dimension, ConvertError, UnitRegistry};
+
+    #[test]
+    fn test_dimension_arithmetic() {
+        assert_eq!(
+            Dimension::LENGTH.mul(&Dimension::LENGTH),
+            Some(Dimension::AREA)
+        );
+        assert_eq!(
+            Dimension::VOLUME.div(&Dimension::LENGTH),
+            Some(Dimension::AREA)
+        );
+        assert_eq!(Dimension::LENGTH.pow(3), Some(Dimension::VOLUME));
+        assert_eq!(Dimension::TIME.pow(-1), Some(Dimension::FREQUENCY));
+        assert!(Dimension::LENGTH
+            .div(&Dimension::LENGTH)
+            .unwrap()
+            .is_dimensionless());
+        assert_eq!(
+            Dimension::MASS
+                .mul(&Dimension::AREA)
+                .and_then(|d| d.div(&Dimension::TIME.pow(2)?)),
+            Some(Dimension::ENERGY)
+        );
+        // the exponents out of the i8 range
+        assert_eq!(Dimension::ENERGY.pow(100), None);
+        let length_100 = Dimension::LENGTH.pow(100).unwrap();
+        assert_eq!(length_100.mul(&length_100), None);
+    }
+
+    #[test]
+    fn test_dimension_display() {
+        assert_eq!(Dimension::LENGTH.to_string(), "L");
+        assert_eq!(Dimension::ENERGY.to_string(), "L²·M·T⁻²");
+        assert_eq!(Dimension::NONE.to_string(), "1");
+    }
+
+    #[test]
+    fn test_resolve_unit() {
+        let registry = UnitRegistry::default();
+
+        // a single unit
+        let unit = registry.resolve_unit("km", None).unwrap();
+        assert_eq!(unit.unit_type.as_deref(), Some("LENGTH"));
+        assert_eq!(unit.dimension, Dimension::LENGTH);
+        assert_eq!(unit.ratios, vec![("".to_string(), 1000.0)]);
+
+        // a single unit, preferably of the given unit type
+        let unit = registry.resolve_unit("m", Some("TIME")).unwrap();
+        assert_eq!(unit.abbr, "min");
+        let unit = registry.resolve_unit("m", None).unwrap();
+        assert_eq!(unit.abbr, "m");
+
+        // a quotient of units
+        let unit = registry.resolve_unit("mi/hr", None).unwrap();
+        assert_eq!(unit.abbr, "mi/hr");
+        assert_eq!(unit.unit_type, None);
+        assert_eq!(
+            unit.dimension,
+            Dimension::LENGTH.div(&Dimension::TIME).unwrap()
+        );
+        assert!((unit.ratios[0].1 - 0.44704).abs() < 1e-5);
+
+        // products, quotients and exponents
+        let unit = registry.resolve_unit("kg⋅m/s²", None).unwrap();
+        assert_eq!(unit.abbr, "kg⋅m/s²");
+        assert_eq!(
+            unit.dimension,
+            Dimension::ENERGY.div(&Dimension::LENGTH).unwrap()
+        );
+        let unit = registry.resolve_unit("kg*m/s^2", None).unwrap();
+        assert_eq!(unit.abbr, "kg⋅m/s²");
+        let unit = registry.resolve_unit("J/kg⋅K", None).unwrap();
+        assert_eq!(
+            unit.dimension,
+            Dimension::ENERGY
+                .div(&Dimension::MASS)
+                .and_then(|d| d.div(&Dimension::TEMPERATURE))
+                .unwrap()
+        );
+
+        // a unit with "⋅" in its abbreviation
+        let unit = registry.resolve_unit("kW⋅h/day", None).unwrap();
+        assert_eq!(
+            unit.dimension,
+            Dimension::ENERGY.div(&Dimension::TIME).unwrap()
+        );
+        assert!((unit.ratios[0].1 - 3600000.0 / 86400.0).abs() < 1e-9);
+
+        // ratio variants are carried over
+        let unit = registry.resolve_unit("gal/mi", None).unwrap();
+        assert_eq!(unit.ratios.len(), 2);
+        assert_eq!(unit.ratios[0].0, "Imperial");
+
+        // unknown units
+        assert_eq!(
+            registry.resolve_unit("m/smoot", None).err(),
+            Some(ConvertError::UnknownUnit("m/smoot".to_string()))
+        );
+        assert_eq!(
+            registry.resolve_unit("m^x", None).err(),
+            Some(ConvertError::UnknownUnit("m^x".to_string()))
+        );
+
+        // exponents out of range
+        assert_eq!(
+            registry.resolve_unit("J^100", None).err(),
+            Some(ConvertError::ExponentOutOfRange("J^100".to_string()))
+        );
+        assert_eq!(
+            registry
+                .resolve_unit(
+                    "m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9",
+                    None
+                )
+                .err(),
+            Some(ConvertError::ExponentOutOfRange(
+                "m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9*m^9".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_to_base_from_base() {
+        let registry = UnitRegistry::default();
+        let base = |unit_str: &str, value: f64| {
+            let unit = registry.resolve_unit(unit_str, None).unwrap();
+            let ratio = unit.ratios[0].1;
+            let base = unit.to_base(value, ratio);
+            assert!((unit.from_base(base, ratio) - value).abs() < 1e-9);
+            base
+        };
+        // a linear unit, an affine unit, a reversed affine unit, an inverse unit and a decibel unit
+        assert!((base("km", 1.5) - 1500.0).abs() < 1e-9);
+        assert!((base("°F", 212.0) - 373.15).abs() < 1e-9);
+        assert!((base("°De", 150.0) - 273.15).abs() < 1e-9);
+        assert!((base("min/km", 5.0) - 1000.0 / 300.0).abs() < 1e-9);
+        assert!((base("dBm", 20.0) - 0.1).abs() < 1e-9);
+        // in composed units, an affine unit stands for a difference
+        assert!((base("°C/min", 60.0) - 1.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,277 @@
+extern crate cu;
+
+#[cfg(all(test, feature = "exact"))]
+mod exact_test {
+    use cu::constants::Definition;
+    use cu::exact::{format, from_definition, from_f64, parse_decimal, to_f64, Rational};
+    use cu::expr::Variables;
+    use cu::utils::parse_exact_value;
+    use cu::{ConvertError, UnitRegistry};
+
+    fn rational(numerator: i64, denominator: i64) -> Rational {
+        Rational::new(numerator.into(), denominator.into())
+    }
+
+    fn exact_registry() -> UnitRegistry {
+        let mut registry = UnitRegistry::default();
+        registry.set_exact(true);
+        registry
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal("5"), Some(rational(5, 1)));
+        assert_eq!(parse_decimal("-3.14"), Some(rational(-314, 100)));
+        assert_eq!(parse_decimal("+.5"), Some(rational(1, 2)));
+        assert_eq!(parse_decimal("5."), Some(rational(5, 1)));
+        assert_eq!(parse_decimal("1.5E-9"), Some(rational(15, 10_000_000_000)));
+        assert_eq!(
+            parse_decimal("6.02e23"),
+            Some(rational(602, 1) * rational(10_i64.pow(12), 1) * rational(10_i64.pow(9), 1))
+        );
+        assert_eq!(parse_decimal(""), None);
+        assert_eq!(parse_decimal("."), None);
+        assert_eq!(parse_decimal("-"), None);
+        assert_eq!(parse_decimal("1e"), None);
+        assert_eq!(parse_decimal("abc"), None);
+        // not finite as a f64, or with an exponent out of range (rather than a huge power of 10)
+        assert_eq!(parse_decimal("1e400"), None);
+        assert_eq!(parse_decimal("1e100000000"), None);
+        assert_eq!(parse_decimal("1e-100000000"), None);
+        assert_eq!(parse_decimal("inf"), None);
+    }
+
+    #[test]
+    fn test_parse_exact_value() {
+        assert_eq!(parse_exact_value("1/3 ft"), Some(rational(1, 3)));
+        assert_eq!(parse_exact_value("0.1m"), Some(rational(1, 10)));
+        assert_eq!(parse_exact_value("1:05:30 hr"), Some(rational(131, 120)));
+        assert_eq!(parse_exact_value("5:30"), Some(rational(11, 2)));
+        assert_eq!(parse_exact_value("1/0 m"), None);
+        assert_eq!(parse_exact_value("ft"), None);
+    }
+
+    #[test]
+    fn test_from_f64() {
+        // the decimal number rather than the closest binary fraction
+        assert_eq!(from_f64(0.3048), Some(rational(3048, 10_000)));
+        assert_eq!(from_f64(1e-6), Some(rational(1, 1_000_000)));
+        assert_eq!(from_f64(-273.15), Some(rational(-27315, 100)));
+        assert_eq!(from_f64(1e30), Some(rational(10_i64.pow(15), 1).pow(2)));
+        // an integer with more digits than its shortest decimal number (2^60, the exbi- prefix)
+        assert_eq!(
+            from_f64(1152921504606846976.0),
+            Some(rational(1 << 30, 1).pow(2))
+        );
+        // (not the exact value of a quotient: see test_exact_ratios)
+        assert_eq!(
+            from_f64(5.0 / 9.0),
+            Some(rational(5_555_555_555_555_556, 10_000_000_000_000_000))
+        );
+        assert_eq!(from_f64(f64::INFINITY), None);
+        assert_eq!(from_f64(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format(&rational(1, 3), 2), "0.33");
+        assert_eq!(format(&rational(2, 3), 14), "0.66666666666667");
+        assert_eq!(format(&rational(12, 1), 2), "12");
+        assert_eq!(format(&rational(3, 2), 0), "2");
+        assert_eq!(format(&rational(101, 100), 5), "1.01");
+        // halves are rounded away from zero
+        assert_eq!(format(&rational(1005, 1000), 2), "1.01");
+        assert_eq!(format(&rational(-1005, 1000), 2), "-1.01");
+        assert_eq!(format(&rational(-5, 1000), 2), "-0.01");
+        // without a negative zero
+        assert_eq!(format(&rational(-1, 1000), 2), "0");
+        assert_eq!(format(&rational(-3, 100), 2), "-0.03");
+    }
+
+    #[test]
+    fn test_exact_ratios() {
+        let registry = exact_registry();
+        let unit = |unit_str: &str| registry.find_unit(unit_str, None).unwrap();
+        let exact_ratios = |unit_str: &str| unit(unit_str).exact_ratios.unwrap();
+        assert_eq!(exact_ratios("mph"), vec![rational(1397, 3125)]);
+        assert_eq!(
+            exact_ratios("gal")[1],
+            rational(473_176_473, 125_000_000_000)
+        );
+        // (0.3048 m times 0.45359237 kg times 9.80665 m/s²)
+        assert_eq!(
+            exact_ratios("ft⋅lbf"),
+            vec![
+                rational(3048, 10_000)
+                    * rational(45_359_237, 100_000_000)
+                    * rational(980_665, 100_000)
+            ]
+        );
+        assert_eq!(exact_ratios("°F"), vec![rational(5, 9)]);
+        assert_eq!(
+            from_definition(&unit("°F").offset),
+            Some(rational(45_967, 100) * rational(5, 9))
+        );
+        assert_eq!(exact_ratios("ftUS"), vec![rational(1200, 3937)]);
+        assert_eq!(exact_ratios("km/h"), vec![rational(5, 18)]);
+        // with the factor of the prefix
+        assert_eq!(exact_ratios("kPa"), vec![rational(1000, 1)]);
+        assert_eq!(
+            exact_ratios("EiB"),
+            vec![rational(8, 1) * rational(1 << 30, 1).pow(2)]
+        );
+        // the units of a registry that isn't exact have no exact ratios
+        let unit = UnitRegistry::default().find_unit("mph", None).unwrap();
+        assert_eq!(unit.exact_ratios, None);
+    }
+
+    #[test]
+    fn test_exact_definitions() {
+        // a ratio or offset computed with floating point numbers (e.g. 5.0 / 9.0 rather than
+        // Definition::from(5.0) / 9.0) has the rounded decimal number of its value as its exact value
+        // (0.5555555555555556 rather than 5/9), which only a non-terminating ratio rounds to 16+ digits
+        let missing = |definition: &Definition| {
+            let Some(exact) = from_definition(definition) else {
+                return true;
+            };
+            let formatted = format!("{:e}", definition.value);
+            let digits = formatted
+                .split('e')
+                .next()
+                .unwrap()
+                .chars()
+                .filter(char::is_ascii_digit)
+                .count();
+            let rounded = !exact.is_integer()
+                && digits >= 16
+                && Some(&exact) == from_f64(definition.value).as_ref();
+            let mismatched =
+                (to_f64(&exact) - definition.value).abs() > definition.value.abs() * 1e-15;
+            rounded || mismatched
+        };
+        let mut mismatches = Vec::new();
+        for (unit_type, units) in UnitRegistry::default().unit_types() {
+            for unit in units {
+                for (variant, ratio) in unit.ratios.iter() {
+                    if missing(ratio) {
+                        mismatches.push(format!(
+                            "{}: {} {}: {}",
+                            unit_type, unit.abbr, variant, ratio.value
+                        ));
+                    }
+                }
+                if missing(&unit.offset) {
+                    mismatches.push(format!(
+                        "{}: {} offset: {}",
+                        unit_type, unit.abbr, unit.offset.value
+                    ));
+                }
+            }
+        }
+        assert!(
+            mismatches.is_empty(),
+            "ratios without an exact definition: {:#?}",
+            mismatches
+        );
+    }
+
+    #[test]
+    fn test_convert_exact() {
+        let registry = exact_registry();
+        let test_cases = [
+            ("1.005 km to km", vec!["[LENGTH] 1.005 km = 1.01 km"]),
+            (
+                "1/3 ft to in",
+                vec!["[LENGTH] 0.3333333333333333 ft = 4 in"],
+            ),
+            ("12 in to ft :*", vec!["[LENGTH] 12 in = 1 ft"]),
+            ("1 mi² to m² :*", vec!["[AREA] 1 mi² = 2589988.110336 m²"]),
+            (
+                "1 psi to Pa :*",
+                vec!["[PRESSURE] 1 psi = 6894.75729316836134 Pa"],
+            ),
+            (
+                "1 gal to in³ :*",
+                vec![
+                    "[VOLUME] 1 gal (Imperial) = 277.41943279162149 in³",
+                    "[VOLUME] 1 gal (US liquid) = 231 in³",
+                ],
+            ),
+            (
+                "100 °F to K :*",
+                vec!["[TEMPERATURE] 100 °F = 310.92777777777778 K"],
+            ),
+            (
+                "1:05:30 hr to s",
+                vec!["[TIME] 1.0916666666666666 hr = 3930 s"],
+            ),
+            (
+                "(3 ft + 4 in) * 3 to yd :*",
+                vec!["[LENGTH] (3 ft + 4 in) * 3 = 3.33333333333333 yd"],
+            ),
+            ("5 mi/hr to km/h :*", vec!["[SPEED] 5 mi/hr = 8.04672 km/h"]),
+            (
+                "10 L/100km to mpg :*",
+                vec![
+                    "[FUEL ECONOMY] 10 L/100km = 28.24809363318222 mpg (Imperial)",
+                    "[FUEL ECONOMY] 10 L/100km = 23.52145833333333 mpg (US)",
+                ],
+            ),
+            // the results without an exact value fall back to the floating point ones
+            ("0 dBm to mW", vec!["[POWER] 0 dBm = 1 mW"]),
+            ("1 ft / 0 to m", vec!["[LENGTH] 1 ft / 0 = inf m"]),
+        ];
+        for (input, expected_lines) in test_cases {
+            let conversion = registry.convert(input).unwrap();
+            assert_eq!(conversion.lines(), expected_lines, "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn test_convert_exact_values() {
+        let registry = exact_registry();
+        let conversion = registry.convert("1/3 ft to in").unwrap();
+        assert_eq!(conversion.results[0].exact_value, Some(rational(4, 1)));
+        assert!((conversion.results[0].value - 4.0).abs() < 1e-9);
+        // the level of a decibel unit can't be exact
+        let conversion = registry.convert("3 dBm to mW").unwrap();
+        assert_eq!(conversion.results[0].exact_value, None);
+        // the exact values are kept in the variables
+        let mut variables = Variables::new();
+        let third = registry
+            .evaluate("1/3 ft", None, &Variables::new())
+            .unwrap()
+            .quantity;
+        variables.insert("x".to_string(), third);
+        let conversion = registry.convert_with("x * 3 to ft :*", &variables).unwrap();
+        assert_eq!(conversion.results[0].exact_value, Some(rational(1, 1)));
+        assert_eq!(conversion.lines(), vec!["[LENGTH] x * 3 = 1 ft"]);
+    }
+
+    #[test]
+    fn test_convert_huge_exponents() {
+        // (a value that isn't finite as a f64 is rejected rather than converted exactly)
+        let registry = exact_registry();
+        assert_eq!(
+            registry.convert("1e400 m to ft").err(),
+            Some(ConvertError::NonFiniteValue("1e400".to_string()))
+        );
+        assert_eq!(
+            registry.convert("1e100000000 m to ft").err(),
+            Some(ConvertError::NonFiniteValue("1e100000000".to_string()))
+        );
+        let conversion = registry.convert("1e-100000000 m to ft").unwrap();
+        assert_eq!(conversion.lines(), vec!["[LENGTH] 0 m = 0 ft"]);
+        // a registry that isn't exact doesn't parse the exact values
+        let conversion = UnitRegistry::default().convert("1e300 m to ft").unwrap();
+        assert_eq!(conversion.results[0].exact_value, None);
+    }
+
+    #[test]
+    fn test_convert_not_exact() {
+        // the exact values are only computed for an exact registry
+        let conversion = UnitRegistry::default().convert("1.005 km to km").unwrap();
+        assert_eq!(conversion.results[0].exact_value, None);
+        assert_eq!(conversion.lines(), vec!["[LENGTH] 1.005 km = 1 km"]);
+    }
+}
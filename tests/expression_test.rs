@@ -0,0 +1,211 @@
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+#[derive(Clone)]
+struct TestCase {
+    input: &'static str,
+    expected_output: &'static str,
+}
+
+fn run_test(test_case: TestCase) -> Result<(), String> {
+    let child = Command::new("cargo")
+        .arg("run")
+        .arg("--")
+        .arg(test_case.input)
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to spawn child process");
+
+    // get the stdout from the child process
+    let output = child.wait_with_output().expect("Failed to wait on child");
+    let stdout = String::from_utf8(output.stdout).expect("Failed to convert stdout to string");
+
+    // remove any trailing newlines and trim for clean comparison
+    let stdout_trimmed = stdout.trim();
+    let expected_trimmed = test_case.expected_output.trim();
+
+    if stdout_trimmed == expected_trimmed {
+        Ok(())
+    } else {
+        Err(format!(
+            "Test failed for input: '{}'\nExpected: '{}'\nGot: '{}'",
+            test_case.input, expected_trimmed, stdout_trimmed
+        ))
+    }
+}
+
+fn run_tests(test_cases: Vec<TestCase>) {
+    println!("Running {} test cases...", test_cases.len());
+
+    let mut passed = 0;
+    let mut failed = 0;
+
+    for (i, test_case) in test_cases.iter().enumerate() {
+        print!("Test case {}: '{}' ... ", i + 1, test_case.input);
+        io::stdout().flush().unwrap();
+
+        match run_test(test_case.clone()) {
+            Ok(_) => {
+                passed += 1;
+                println!("PASSED");
+            }
+            Err(err) => {
+                failed += 1;
+                println!("FAILED");
+                println!("{}", err);
+            }
+        }
+    }
+
+    println!("\nTest Results: {} passed, {} failed", passed, failed);
+
+    if failed > 0 {
+        panic!("Some tests failed");
+    }
+}
+
+#[test]
+fn test_arithmetic_expressions() {
+    let test_cases = vec![
+        // addition and multiplication with parentheses
+        TestCase {
+            input: "(3 ft + 4 in) * 2 to cm",
+            expected_output: "[LENGTH] (3 ft + 4 in) * 2 = 203.2 cm",
+        },
+        // division by a number
+        TestCase {
+            input: "2 GB / 8 to MB",
+            expected_output: "[DIGITAL STORAGE] 2 GB / 8 = 250 MB",
+        },
+        // subtraction
+        TestCase {
+            input: "5ft - 10in = in",
+            expected_output: "[LENGTH] 5 ft - 10 in = 50 in",
+        },
+        // mixed units as operands
+        TestCase {
+            input: "2 * 3 ft 4 in to in",
+            expected_output: "[LENGTH] 2 * 3 ft 4 in = 80 in",
+        },
+        // operators without spaces around parentheses
+        TestCase {
+            input: "(1 m)-10 cm = cm",
+            expected_output: "[LENGTH] (1 m) - 10 cm = 90 cm",
+        },
+        // unary minus and alternative operator symbols
+        TestCase {
+            input: "-(3 m × 2) ÷ 4 = cm",
+            expected_output: "[LENGTH] -(3 m * 2) / 4 = -150 cm",
+        },
+        // ratio of two quantities as a factor
+        TestCase {
+            input: "1 km * (1 m / 1 ft) = km",
+            expected_output: "[LENGTH] 1 km * (1 m / 1 ft) = 3.28 km",
+        },
+        // expressions with multiple ratio variants
+        TestCase {
+            input: "1 gal / 2 = qt",
+            expected_output: "\
+            [VOLUME] 1 gal / 2 (Imperial) = 2 qt (Imperial)\n\
+            [VOLUME] 1 gal / 2 (Imperial) = 2.4 qt (US liquid)\n\
+            [VOLUME] 1 gal / 2 (US liquid) = 1.67 qt (Imperial)\n\
+            [VOLUME] 1 gal / 2 (US liquid) = 2 qt (US liquid)",
+        },
+    ];
+
+    run_tests(test_cases);
+}
+
+#[test]
+fn test_compound_units() {
+    let test_cases = vec![
+        // speed
+        TestCase {
+            input: "60 mi/hr to km/hr",
+            expected_output: "[SPEED] 60 mi/hr = 96.56 km/hr",
+        },
+        TestCase {
+            input: "60 mi/hr to m/s",
+            expected_output: "[SPEED] 60 mi/hr = 26.82 m/s",
+        },
+        // energy per time
+        TestCase {
+            input: "5 kW⋅h/day to J/s",
+            expected_output: "[POWER] 5 kW⋅h/d = 208.33 J/s",
+        },
+        // exponents
+        TestCase {
+            input: "3 m^2 = ft^2",
+            expected_output: "[AREA] 3 m² = 32.29 ft²",
+        },
+        // a compound unit into a unit of a unit type with the same dimension
+        TestCase {
+            input: "1 kg⋅m²/s² to J",
+            expected_output: "[ENERGY] 1 kg⋅m²/s² = 1 J",
+        },
+        // quantities multiplied/divided in an expression
+        TestCase {
+            input: "100 km / 2 hr to m/s",
+            expected_output: "[SPEED] 100 km / 2 hr = 13.89 m/s",
+        },
+        TestCase {
+            input: "2 m * 3 m = ft2",
+            expected_output: "[AREA] 2 m * 3 m = 64.58 ft²",
+        },
+        // an ambiguous target unit resolved by the source
+        TestCase {
+            input: "90 s + 30 s = m",
+            expected_output: "[TIME] 90 s + 30 s = 2 min",
+        },
+        // an ambiguous target unit of the same unit type as the ambiguous source
+        TestCase {
+            input: "1 m + 1 m = m",
+            expected_output: "[LENGTH] 1 m + 1 m = 2 m",
+        },
+        // dimension mismatch
+        TestCase {
+            input: "10 m/s to km",
+            expected_output: "[ Incompatible unit types: 'SPEED' and 'LENGTH' ]",
+        },
+    ];
+
+    run_tests(test_cases);
+}
+
+#[test]
+fn test_arithmetic_expression_failures() {
+    let test_cases = vec![
+        // adding a number to a quantity
+        TestCase {
+            input: "1 m + 2 = m",
+            expected_output: "[ Incompatible unit types: 'LENGTH' and 'NUMBER' ]",
+        },
+        // the result is a number, not a length
+        TestCase {
+            input: "1 m / 2 m = m",
+            expected_output:
+                "[ Incompatible unit types: 'NUMBER' and 'm', which can be Meter (LENGTH) or Minute (TIME) ]",
+        },
+        // adding quantities of different dimensions
+        TestCase {
+            input: "(1 m + 2 kg) * 2 = m",
+            expected_output: "[ Incompatible unit types: 'LENGTH' and 'MASS' ]",
+        },
+        // unbalanced parentheses
+        TestCase {
+            input: "(1 m + 2 cm = m",
+            expected_output: "[ Invalid expression: missing closing parenthesis ]",
+        },
+        TestCase {
+            input: "1 m + 2 cm) = m",
+            expected_output: "[ Invalid expression: unexpected closing parenthesis ]",
+        },
+        // missing operand
+        TestCase {
+            input: "1 m + = m",
+            expected_output: "[ Invalid expression: unexpected end of expression ]",
+        },
+    ];
+
+    run_tests(test_cases);
+}
@@ -65,7 +65,7 @@ fn run_tests(test_cases: Vec<TestCase>) {
 #[test]
 fn test_formula_field_usage() {
     // this test specifically tests temperature conversions
-    // which require using formulas instead of simple ratio conversions
+    // which use an offset (affine units) instead of simple ratio conversions
     let test_cases = vec![
         TestCase {
             input: "32°F = °C",
@@ -117,6 +117,21 @@ fn test_formula_field_usage() {
             expected_output: "[TEMPERATURE] 36.6 °C = 309.75 K",
             description: "Body temperature: Celsius to Kelvin",
         },
+        TestCase {
+            input: "100°C = °R",
+            expected_output: "[TEMPERATURE] 100 °C = 671.67 °R",
+            description: "Boiling point: Celsius to Rankine",
+        },
+        TestCase {
+            input: "25°C = °De",
+            expected_output: "[TEMPERATURE] 25 °C = 112.5 °De",
+            description: "Celsius to Delisle (a reversed scale)",
+        },
+        TestCase {
+            input: "60 °Rø = °N",
+            expected_output: "[TEMPERATURE] 60 °Rø = 33 °N",
+            description: "Rømer to Newton",
+        },
     ];
 
     run_tests(test_cases);
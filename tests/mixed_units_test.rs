@@ -144,6 +144,21 @@ fn test_edge_cases() {
             input: "5/2ft 3/4in = km:*",
             expected_output: "[LENGTH] 2.5 ft 0.75 in = 0.00078105 km",
         },
+        // scientific notation
+        TestCase {
+            input: "6.02e23 eV = kJ",
+            expected_output: "[ENERGY] 6.02e23 eV = 96.45 kJ",
+        },
+        // scientific notation with a negative exponent
+        TestCase {
+            input: "1.5E-9 s 2.5e-10s = ns",
+            expected_output: "[TIME] 1.75e-9 s = 1.75 ns",
+        },
+        // explicit positive sign and leading decimal point
+        TestCase {
+            input: "+3 m .5 ft = ft",
+            expected_output: "[LENGTH] 3 m 0.5 ft = 10.34 ft",
+        },
     ];
 
     run_tests(test_cases);
@@ -0,0 +1,60 @@
+extern crate cu;
+
+#[cfg(test)]
+mod output_test {
+    use cu::convert;
+    use cu::output::{OutputFormat, TSV_HEADER};
+
+    #[test]
+    fn test_output_format() {
+        assert_eq!("json".parse::<OutputFormat>(), Ok(OutputFormat::Json));
+        assert_eq!("TSV".parse::<OutputFormat>(), Ok(OutputFormat::Tsv));
+        assert_eq!("text".parse::<OutputFormat>(), Ok(OutputFormat::Text));
+        assert_eq!(
+            "xml".parse::<OutputFormat>(),
+            Err("[ Unknown output format: xml (use text, json or tsv) ]".to_string())
+        );
+    }
+
+    #[test]
+    fn test_json_records() {
+        let conversion = convert("5ft 10in to m").unwrap();
+        assert_eq!(
+            conversion.records(OutputFormat::Json),
+            vec!["{\"category\":\"LENGTH\",\"source\":\"5 ft 10 in\",\
+                \"sources\":[{\"value\":5.0,\"unit\":\"ft\"},{\"value\":10.0,\"unit\":\"in\"}],\
+                \"source_variant\":null,\"target_abbr\":\"m\",\"target_name\":\"Meter\",\
+                \"target_variant\":null,\"value\":1.778,\"formatted\":\"1.78\"}"
+                .to_string()]
+        );
+
+        // ratio variants and scientific notation
+        let conversion = convert("1.5e-9 gal = fl oz:3").unwrap();
+        let records = conversion.json_records();
+        assert_eq!(records.len(), 4);
+        assert!(records[1].contains("\"sources\":[{\"value\":1.5e-9,\"unit\":\"gal\"}]"));
+        assert!(records[1].contains("\"source_variant\":\"Imperial\""));
+        assert!(records[1].contains("\"target_variant\":\"US\""));
+        assert!(records[1].contains("\"target_name\":\"Fluid Ounce • Imperial / US\""));
+    }
+
+    #[test]
+    fn test_tsv_records() {
+        assert_eq!(TSV_HEADER.split('\t').count(), 9);
+        let conversion = convert("1 gal = qt:1").unwrap();
+        let records = conversion.records(OutputFormat::Tsv);
+        assert_eq!(records.len(), 4);
+        assert_eq!(
+            records[1],
+            "VOLUME\t1 gal\t1 gal\tImperial\tqt\tQuart • Imperial / US liquid\tUS liquid\t4.80379970201942\t4.8"
+        );
+        let conversion = convert("(3 ft + 4 in) * 2 to cm").unwrap();
+        assert_eq!(
+            conversion.tsv_records(),
+            vec![
+                "LENGTH\t(3 ft + 4 in) * 2\t3 ft 4 in\t\tcm\tCentimeter\t\t203.2\t203.2"
+                    .to_string()
+            ]
+        );
+    }
+}
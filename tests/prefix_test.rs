@@ -0,0 +1,151 @@
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+#[derive(Clone)]
+struct TestCase {
+    input: &'static str,
+    expected_output: &'static str,
+}
+
+fn run_test(test_case: TestCase) -> Result<(), String> {
+    let child = Command::new("cargo")
+        .arg("run")
+        .arg("--")
+        .arg(test_case.input)
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to spawn child process");
+
+    // get the stdout from the child process
+    let output = child.wait_with_output().expect("Failed to wait on child");
+    let stdout = String::from_utf8(output.stdout).expect("Failed to convert stdout to string");
+
+    // remove any trailing newlines and trim for clean comparison
+    let stdout_trimmed = stdout.trim();
+    let expected_trimmed = test_case.expected_output.trim();
+
+    if stdout_trimmed == expected_trimmed {
+        Ok(())
+    } else {
+        Err(format!(
+            "Test failed for input: '{}'\nExpected: '{}'\nGot: '{}'",
+            test_case.input, expected_trimmed, stdout_trimmed
+        ))
+    }
+}
+
+fn run_tests(test_cases: Vec<TestCase>) {
+    println!("Running {} test cases...", test_cases.len());
+
+    let mut passed = 0;
+    let mut failed = 0;
+
+    for (i, test_case) in test_cases.iter().enumerate() {
+        print!("Test case {}: '{}' ... ", i + 1, test_case.input);
+        io::stdout().flush().unwrap();
+
+        match run_test(test_case.clone()) {
+            Ok(_) => {
+                passed += 1;
+                println!("PASSED");
+            }
+            Err(err) => {
+                failed += 1;
+                println!("FAILED");
+                println!("{}", err);
+            }
+        }
+    }
+
+    println!("\nTest Results: {} passed, {} failed", passed, failed);
+
+    if failed > 0 {
+        panic!("Some tests failed");
+    }
+}
+
+#[test]
+fn test_si_prefixes() {
+    let test_cases = vec![
+        TestCase {
+            input: "1 GJ = kW⋅h",
+            expected_output: "[ENERGY] 1 GJ = 277.78 kW⋅h",
+        },
+        TestCase {
+            input: "2 THz = GHz",
+            expected_output: "[FREQUENCY] 2 THz = 2000 GHz",
+        },
+        // case-sensitive prefixes: millihertz, not megahertz
+        TestCase {
+            input: "500 mHz = Hz",
+            expected_output: "[FREQUENCY] 500 mHz = 0.5 Hz",
+        },
+        TestCase {
+            input: "1013 hPa = atm:3",
+            expected_output: "[PRESSURE] 1013 hPa = 1 atm",
+        },
+        TestCase {
+            input: "1 MPa = bar",
+            expected_output: "[PRESSURE] 1 MPa = 10 bar",
+        },
+        // alternative micro symbol
+        TestCase {
+            input: "5 um = nm",
+            expected_output: "[LENGTH] 5 μm = 5000 nm",
+        },
+        TestCase {
+            input: "300 pm = nm",
+            expected_output: "[LENGTH] 300 pm = 0.3 nm",
+        },
+        TestCase {
+            input: "33 cl 5 dl = ml",
+            expected_output: "[VOLUME] 5 dl 33 cl = 830 ml",
+        },
+        TestCase {
+            input: "2 Mg = kg",
+            expected_output: "[MASS] 2 Mg = 2000 kg",
+        },
+        // prefixed unit names
+        TestCase {
+            input: "3 kiloseconds = min",
+            expected_output: "[TIME] 3 ks = 50 min",
+        },
+        // prefixed units within compound units
+        TestCase {
+            input: "1 km/ks = m/s",
+            expected_output: "[SPEED] 1 km/ks = 1 m/s",
+        },
+    ];
+
+    run_tests(test_cases);
+}
+
+#[test]
+fn test_iec_prefixes() {
+    let test_cases = vec![
+        TestCase {
+            input: "1 MiB = kB",
+            expected_output: "[DIGITAL STORAGE] 1 MiB = 1048.58 kB",
+        },
+        TestCase {
+            input: "2 mebibytes = KiB",
+            expected_output: "[DIGITAL STORAGE] 2 MiB = 2048 KiB",
+        },
+        TestCase {
+            input: "1 EiB = PiB",
+            expected_output: "[DIGITAL STORAGE] 1 EiB = 1024 PiB",
+        },
+        // case-sensitive bits and bytes
+        TestCase {
+            input: "1 EB = Eb",
+            expected_output: "[DIGITAL STORAGE] 1 EB = 8 Eb",
+        },
+        // digital storage units don't accept prefixes below kilo
+        TestCase {
+            input: "1 cB = B",
+            expected_output: "[ Unit 'cB' not found in type 'DIGITAL STORAGE' ]",
+        },
+    ];
+
+    run_tests(test_cases);
+}
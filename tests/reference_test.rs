@@ -0,0 +1,301 @@
+extern crate cu;
+
+#[cfg(test)]
+mod reference_test {
+    use std::f64::consts::PI;
+
+    use cu::UnitRegistry;
+
+    // (unit type, abbreviation, variant, ratio, offset) of every built-in unit, typed from the
+    // published definitions rather than from the constants the units are derived from:
+    // the exact decimal values (some with more digits than a f64 holds), or the defining quotients
+    // of the values that don't terminate
+    #[rustfmt::skip]
+    #[allow(clippy::excessive_precision)]
+    const REFERENCE: &[(&str, &str, &str, f64, f64)] = &[
+            // NIST SP 811 B.8
+            ("AREA", "m²", "", 1.0, 0.0),
+            ("AREA", "km²", "", 1000000.0, 0.0),
+            ("AREA", "mi²", "", 2589988.110336, 0.0),
+            ("AREA", "yd²", "", 0.83612736, 0.0),
+            ("AREA", "ft²", "", 0.09290304, 0.0),
+            ("AREA", "in²", "", 0.00064516, 0.0),
+            ("AREA", "ha", "", 10000.0, 0.0),
+            ("AREA", "a", "", 4046.8564224, 0.0),
+            // IEC 80000-13 (bits, bytes of 8 bits, SI and IEC prefixes)
+            ("DATA RATE", "bps", "", 1.0, 0.0),
+            ("DATA RATE", "kbps", "", 1000.0, 0.0),
+            ("DATA RATE", "Mbps", "", 1000000.0, 0.0),
+            ("DATA RATE", "Gbps", "", 1000000000.0, 0.0),
+            ("DATA RATE", "B/s", "", 8.0, 0.0),
+            ("DATA RATE", "kB/s", "", 8000.0, 0.0),
+            ("DATA RATE", "MB/s", "", 8000000.0, 0.0),
+            ("DATA RATE", "MiB/s", "", 8388608.0, 0.0),
+            ("DATA RATE", "GB/s", "", 8000000000.0, 0.0),
+            ("DATA RATE", "GiB/s", "", 8589934592.0, 0.0),
+            // IEC 80000-13
+            ("DIGITAL STORAGE", "b", "", 1.0, 0.0),
+            ("DIGITAL STORAGE", "kb", "", 1000.0, 0.0),
+            ("DIGITAL STORAGE", "Kib", "", 1024.0, 0.0),
+            ("DIGITAL STORAGE", "Mb", "", 1000000.0, 0.0),
+            ("DIGITAL STORAGE", "Mib", "", 1048576.0, 0.0),
+            ("DIGITAL STORAGE", "Gb", "", 1000000000.0, 0.0),
+            ("DIGITAL STORAGE", "Gib", "", 1073741824.0, 0.0),
+            ("DIGITAL STORAGE", "Tb", "", 1000000000000.0, 0.0),
+            ("DIGITAL STORAGE", "Tib", "", 1099511627776.0, 0.0),
+            ("DIGITAL STORAGE", "Pb", "", 1000000000000000.0, 0.0),
+            ("DIGITAL STORAGE", "Pib", "", 1125899906842624.0, 0.0),
+            ("DIGITAL STORAGE", "B", "", 8.0, 0.0),
+            ("DIGITAL STORAGE", "kB", "", 8000.0, 0.0),
+            ("DIGITAL STORAGE", "KiB", "", 8192.0, 0.0),
+            ("DIGITAL STORAGE", "MB", "", 8000000.0, 0.0),
+            ("DIGITAL STORAGE", "MiB", "", 8388608.0, 0.0),
+            ("DIGITAL STORAGE", "GB", "", 8000000000.0, 0.0),
+            ("DIGITAL STORAGE", "GiB", "", 8589934592.0, 0.0),
+            ("DIGITAL STORAGE", "TB", "", 8000000000000.0, 0.0),
+            ("DIGITAL STORAGE", "TiB", "", 8796093022208.0, 0.0),
+            ("DIGITAL STORAGE", "PB", "", 8000000000000000.0, 0.0),
+            ("DIGITAL STORAGE", "PiB", "", 9007199254740992.0, 0.0),
+            // SI Brochure (coherent derived units), ampere-hour: 3600 C
+            ("ELECTRIC CAPACITANCE", "F", "", 1.0, 0.0),
+            ("ELECTRIC CHARGE", "C", "", 1.0, 0.0),
+            ("ELECTRIC CHARGE", "Ah", "", 3600.0, 0.0),
+            ("ELECTRIC CONDUCTANCE", "S", "", 1.0, 0.0),
+            ("ELECTRIC CURRENT", "A", "", 1.0, 0.0),
+            ("ELECTRIC RESISTANCE", "Ω", "", 1.0, 0.0),
+            // NIST SP 811 B.8 (thermochemical calorie, IT Btu, EC therm of 100 000 Btu), SI Brochure (e)
+            ("ENERGY", "J", "", 1.0, 0.0),
+            ("ENERGY", "kJ", "", 1000.0, 0.0),
+            ("ENERGY", "cal", "", 4.184, 0.0),
+            ("ENERGY", "kcal", "", 4184.0, 0.0),
+            ("ENERGY", "W⋅h", "", 3600.0, 0.0),
+            ("ENERGY", "kW⋅h", "", 3600000.0, 0.0),
+            ("ENERGY", "eV", "", 1.602176634e-19, 0.0),
+            ("ENERGY", "Btu", "", 1055.05585262, 0.0),
+            ("ENERGY", "thm", "", 105505585.262, 0.0),
+            ("ENERGY", "ft⋅lbf", "", 1.3558179483314004, 0.0),
+            // NIST SP 811 B.8
+            ("FORCE", "N", "", 1.0, 0.0),
+            ("FORCE", "kgf", "", 9.80665, 0.0),
+            ("FORCE", "lbf", "", 4.4482216152605, 0.0),
+            ("FORCE", "kip", "", 4448.2216152605, 0.0),
+            ("FORCE", "pdl", "", 0.138254954376, 0.0),
+            ("FORCE", "dyn", "", 1e-05, 0.0),
+            // SI Brochure
+            ("FREQUENCY", "Hz", "", 1.0, 0.0),
+            ("FREQUENCY", "kHz", "", 1000.0, 0.0),
+            ("FREQUENCY", "MHz", "", 1000000.0, 0.0),
+            ("FREQUENCY", "GHz", "", 1000000000.0, 0.0),
+            // NIST SP 811 B.8 (mile and gallons), in m/m³
+            ("FUEL ECONOMY", "km/L", "", 1000000.0, 0.0),
+            ("FUEL ECONOMY", "mpg", "Imperial", 1609.344 / 0.00454609, 0.0),
+            ("FUEL ECONOMY", "mpg", "US", 1609.344 / 0.003785411784, 0.0),
+            ("FUEL ECONOMY", "mi/L", "", 1609344.0, 0.0),
+            ("FUEL ECONOMY", "L/100km", "", 100000000.0, 0.0),
+            // SI Brochure
+            ("INDUCTANCE", "H", "", 1.0, 0.0),
+            // NIST SP 811 B.8, SI Brochure (Å), CODATA 2018 (Planck length), IAU 2012 B2 and 2015 B2 (au, pc)
+            ("LENGTH", "m", "", 1.0, 0.0),
+            ("LENGTH", "km", "", 1000.0, 0.0),
+            ("LENGTH", "cm", "", 0.01, 0.0),
+            ("LENGTH", "mm", "", 0.001, 0.0),
+            ("LENGTH", "μm", "", 1e-06, 0.0),
+            ("LENGTH", "nm", "", 1e-09, 0.0),
+            ("LENGTH", "pm", "", 1e-12, 0.0),
+            ("LENGTH", "fm", "", 1e-15, 0.0),
+            ("LENGTH", "Å", "", 1e-10, 0.0),
+            ("LENGTH", "lP", "", 1.616255e-35, 0.0),
+            ("LENGTH", "mi", "", 1609.344, 0.0),
+            ("LENGTH", "nmi", "", 1852.0, 0.0),
+            ("LENGTH", "yd", "", 0.9144, 0.0),
+            ("LENGTH", "ft", "", 0.3048, 0.0),
+            ("LENGTH", "in", "", 0.0254, 0.0),
+            ("LENGTH", "ftUS", "", 1200.0 / 3937.0, 0.0),
+            ("LENGTH", "mil", "", 2.54e-05, 0.0),
+            ("LENGTH", "ftm", "", 1.8288, 0.0),
+            ("LENGTH", "rd", "International", 5.0292, 0.0),
+            ("LENGTH", "rd", "US survey", 16.5 * 1200.0 / 3937.0, 0.0),
+            ("LENGTH", "ch", "International", 20.1168, 0.0),
+            ("LENGTH", "ch", "US survey", 66.0 * 1200.0 / 3937.0, 0.0),
+            ("LENGTH", "fur", "International", 201.168, 0.0),
+            ("LENGTH", "fur", "US survey", 660.0 * 1200.0 / 3937.0, 0.0),
+            ("LENGTH", "lea", "International", 4828.032, 0.0),
+            ("LENGTH", "lea", "US survey", 15840.0 * 1200.0 / 3937.0, 0.0),
+            ("LENGTH", "cbl", "International", 185.2, 0.0),
+            ("LENGTH", "cbl", "Imperial", 185.3184, 0.0),
+            ("LENGTH", "cbl", "US", 219.456, 0.0),
+            ("LENGTH", "pt", "", 0.0254 / 72.0, 0.0),
+            ("LENGTH", "pica", "", 0.0254 / 6.0, 0.0),
+            ("LENGTH", "au", "", 149597870700.0, 0.0),
+            ("LENGTH", "ls", "", 299792458.0, 0.0),
+            ("LENGTH", "ly", "", 9460730472580800.0, 0.0),
+            ("LENGTH", "pc", "", 3.0856775814913673e16, 0.0),
+            // NIST SP 811 B.8
+            ("MASS", "kg", "", 1.0, 0.0),
+            ("MASS", "t", "Metric", 1000.0, 0.0),
+            ("MASS", "t", "Imperial", 1016.0469088, 0.0),
+            ("MASS", "t", "US", 907.18474, 0.0),
+            ("MASS", "gm", "", 0.001, 0.0),
+            ("MASS", "mg", "", 1e-06, 0.0),
+            ("MASS", "µg", "", 1e-09, 0.0),
+            ("MASS", "st", "", 6.35029318, 0.0),
+            ("MASS", "lb", "", 0.45359237, 0.0),
+            ("MASS", "oz", "", 0.028349523125, 0.0),
+            // SI Brochure (°, ′, ″), NIST SP 811 B.8 (gon)
+            ("PLANE ANGLE", "rad", "", 1.0, 0.0),
+            ("PLANE ANGLE", "°", "", PI / 180.0, 0.0),
+            ("PLANE ANGLE", "ᵍ", "", PI / 200.0, 0.0),
+            ("PLANE ANGLE", "mrad", "", 0.001, 0.0),
+            ("PLANE ANGLE", "arcmin", "", PI / 10800.0, 0.0),
+            ("PLANE ANGLE", "arcsec", "", PI / 648000.0, 0.0),
+            // NIST SP 811 B.8 (horsepowers, IT Btu per hour, ton of refrigeration of 12 000 Btu/h)
+            ("POWER", "W", "", 1.0, 0.0),
+            ("POWER", "hp", "Mechanical", 745.69987158227022, 0.0),
+            ("POWER", "hp", "Metric", 735.49875, 0.0),
+            ("POWER", "hp", "Electrical", 746.0, 0.0),
+            ("POWER", "BTU/h", "", 1055.05585262 / 3600.0, 0.0),
+            ("POWER", "TR", "", 12000.0 * 1055.05585262 / 3600.0, 0.0),
+            ("POWER", "erg/s", "", 1e-07, 0.0),
+            ("POWER", "dBm", "", 0.001, 0.0),
+            ("POWER", "dBW", "", 1.0, 0.0),
+            // NIST SP 811 B.8
+            ("PRESSURE", "Pa", "", 1.0, 0.0),
+            ("PRESSURE", "bar", "", 100000.0, 0.0),
+            ("PRESSURE", "psi", "", 4.4482216152605 / 0.00064516, 0.0),
+            ("PRESSURE", "atm", "", 101325.0, 0.0),
+            ("PRESSURE", "Torr", "", 101325.0 / 760.0, 0.0),
+            // NIST SP 811 B.8, SI Brochure (c), ISA sea level speed of sound (not exact)
+            ("SPEED", "m/s", "", 1.0, 0.0),
+            ("SPEED", "km/h", "", 1000.0 / 3600.0, 0.0),
+            ("SPEED", "mph", "", 0.44704, 0.0),
+            ("SPEED", "kn", "", 1852.0 / 3600.0, 0.0),
+            ("SPEED", "ft/s", "", 0.3048, 0.0),
+            ("SPEED", "Ma", "", 340.294, 0.0),
+            ("SPEED", "c", "", 299792458.0, 0.0),
+            ("SPEED", "min/km", "", 1000.0 / 60.0, 0.0),
+            ("SPEED", "min/mi", "", 26.8224, 0.0),
+            // the definitions of the scales (the value in kelvins is the value times the ratio plus the offset)
+            ("TEMPERATURE", "K", "", 1.0, 0.0),
+            ("TEMPERATURE", "°C", "", 1.0, 273.15),
+            ("TEMPERATURE", "°F", "", 5.0 / 9.0, 459.67 * 5.0 / 9.0),
+            ("TEMPERATURE", "°R", "", 5.0 / 9.0, 0.0),
+            ("TEMPERATURE", "°Ré", "", 1.25, 273.15),
+            ("TEMPERATURE", "°Rø", "", 40.0 / 21.0, 273.15 - 7.5 * 40.0 / 21.0),
+            ("TEMPERATURE", "°De", "", -2.0 / 3.0, 373.15),
+            ("TEMPERATURE", "°N", "", 100.0 / 33.0, 273.15),
+            // the ratios of the temperature scales
+            ("TEMPERATURE DIFFERENCE", "ΔK", "", 1.0, 0.0),
+            ("TEMPERATURE DIFFERENCE", "Δ°C", "", 1.0, 0.0),
+            ("TEMPERATURE DIFFERENCE", "Δ°F", "", 5.0 / 9.0, 0.0),
+            ("TEMPERATURE DIFFERENCE", "Δ°R", "", 5.0 / 9.0, 0.0),
+            ("TEMPERATURE DIFFERENCE", "Δ°Ré", "", 1.25, 0.0),
+            ("TEMPERATURE DIFFERENCE", "Δ°Rø", "", 40.0 / 21.0, 0.0),
+            ("TEMPERATURE DIFFERENCE", "Δ°De", "", -2.0 / 3.0, 0.0),
+            ("TEMPERATURE DIFFERENCE", "Δ°N", "", 100.0 / 33.0, 0.0),
+            // SI Brochure (min, h, d), the mean Gregorian year of 365.2425 days
+            ("TIME", "s", "", 1.0, 0.0),
+            ("TIME", "ns", "", 1e-09, 0.0),
+            ("TIME", "μs", "", 1e-06, 0.0),
+            ("TIME", "ms", "", 0.001, 0.0),
+            ("TIME", "min", "", 60.0, 0.0),
+            ("TIME", "hr", "", 3600.0, 0.0),
+            ("TIME", "d", "", 86400.0, 0.0),
+            ("TIME", "wk", "", 604800.0, 0.0),
+            ("TIME", "mth", "", 2629746.0, 0.0),
+            ("TIME", "yr", "", 31556952.0, 0.0),
+            ("TIME", "dec", "", 315569520.0, 0.0),
+            ("TIME", "cent", "", 3155695200.0, 0.0),
+            // NIST SP 811 B.8
+            ("TORQUE", "N⋅m", "", 1.0, 0.0),
+            ("TORQUE", "lbf⋅ft", "", 1.3558179483314004, 0.0),
+            ("TORQUE", "lbf⋅in", "", 0.1129848290276167, 0.0),
+            ("TORQUE", "kgf⋅m", "", 9.80665, 0.0),
+            // SI Brochure
+            ("VOLTAGE", "V", "", 1.0, 0.0),
+            // NIST Handbook 44 C (US), Weights and Measures Act 1985 (imperial), 21 CFR 101.9 (US legal cup)
+            ("VOLUME", "m³", "", 1.0, 0.0),
+            ("VOLUME", "l", "", 0.001, 0.0),
+            ("VOLUME", "ml", "", 1e-06, 0.0),
+            ("VOLUME", "gal", "Imperial", 0.00454609, 0.0),
+            ("VOLUME", "gal", "US liquid", 0.003785411784, 0.0),
+            ("VOLUME", "qt", "Imperial", 0.0011365225, 0.0),
+            ("VOLUME", "qt", "US liquid", 0.000946352946, 0.0),
+            ("VOLUME", "pt", "Imperial", 0.00056826125, 0.0),
+            ("VOLUME", "pt", "US liquid", 0.000473176473, 0.0),
+            ("VOLUME", "c", "Imperial", 0.000284130625, 0.0),
+            ("VOLUME", "c", "US legal", 0.00024, 0.0),
+            ("VOLUME", "fl oz", "Imperial", 2.84130625e-05, 0.0),
+            ("VOLUME", "fl oz", "US", 2.95735295625e-05, 0.0),
+            ("VOLUME", "tbsp", "Imperial", 1.77581640625e-05, 0.0),
+            ("VOLUME", "tbsp", "US", 1.478676478125e-05, 0.0),
+            ("VOLUME", "tsp", "Imperial", 0.00454609 / 768.0, 0.0),
+            ("VOLUME", "tsp", "US", 4.92892159375e-06, 0.0),
+            ("VOLUME", "ft³", "", 0.028316846592, 0.0),
+            ("VOLUME", "in³", "", 1.6387064e-05, 0.0),
+    ];
+
+    fn close(actual: f64, expected: f64) -> bool {
+        actual == expected || ((actual - expected) / expected).abs() < 1e-12
+    }
+
+    #[test]
+    fn test_built_in_units_match_reference_values() {
+        let mut mismatches = Vec::new();
+        let mut missing = Vec::new();
+        for (unit_type, units) in UnitRegistry::default().unit_types() {
+            for unit in units {
+                for (variant, ratio) in unit.ratios.iter() {
+                    match REFERENCE
+                        .iter()
+                        .find(|r| r.0 == unit_type && r.1 == unit.abbr && r.2 == variant)
+                    {
+                        Some(&(_, _, _, expected_ratio, expected_offset)) => {
+                            if !close(ratio.value, expected_ratio)
+                                || !close(unit.offset.value, expected_offset)
+                            {
+                                mismatches.push(format!(
+                                    "{}: {} {}: {} + {} (expected {} + {})",
+                                    unit_type,
+                                    unit.abbr,
+                                    variant,
+                                    ratio.value,
+                                    unit.offset.value,
+                                    expected_ratio,
+                                    expected_offset
+                                ));
+                            }
+                        }
+                        None => missing.push(format!("{}: {} {}", unit_type, unit.abbr, variant)),
+                    }
+                }
+            }
+        }
+        assert!(mismatches.is_empty(), "mismatched units: {:#?}", mismatches);
+        assert!(
+            missing.is_empty(),
+            "units without a reference value: {:#?}",
+            missing
+        );
+    }
+
+    #[test]
+    fn test_reference_values_are_built_in_units() {
+        let registry = UnitRegistry::default();
+        let stale: Vec<_> = REFERENCE
+            .iter()
+            .filter(|(unit_type, abbr, variant, _, _)| {
+                !registry.unit_types().any(|(t, units)| {
+                    t == *unit_type
+                        && units
+                            .iter()
+                            .any(|u| u.abbr == *abbr && u.ratios.iter().any(|(v, _)| v == variant))
+                })
+            })
+            .collect();
+        assert!(
+            stale.is_empty(),
+            "reference values without a unit: {:#?}",
+            stale
+        );
+    }
+}
@@ -0,0 +1,51 @@
+extern crate cu;
+
+#[cfg(test)]
+mod registry_test {
+    use cu::dimension::Dimension;
+    use cu::units::Unit;
+    use cu::{ConvertError, UnitRegistry};
+
+    #[test]
+    fn test_registry_add_units() {
+        let mut registry = UnitRegistry::default();
+        registry
+            .add_unit(
+                "LENGTH",
+                Unit::new("Rack Unit", "U", Dimension::LENGTH, 0.04445),
+            )
+            .add_alias("LENGTH", "U", "rack units")
+            .unwrap();
+        assert_eq!(
+            registry.convert("2 rack units = cm").unwrap().lines(),
+            vec!["[LENGTH] 2 U = 8.89 cm"]
+        );
+
+        // the other registries are not affected
+        assert!(UnitRegistry::default()
+            .find_unit("U", Some("LENGTH"))
+            .is_none());
+        assert_eq!(
+            registry.add_alias("SPEED OF DARK", "U", "u").err(),
+            Some(ConvertError::UnknownUnitType("SPEED OF DARK".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_registry_remove_units() {
+        let mut registry = UnitRegistry::default();
+        registry.remove_unit("LENGTH", "m").unwrap();
+        assert_eq!(
+            registry.convert("5 m = s").unwrap().lines(),
+            vec!["[TIME] 5 min = 300 s"]
+        );
+
+        registry.retain_unit_types(&["length", "mass"]);
+        assert_eq!(
+            registry.unit_types().map(|(t, _)| t).collect::<Vec<_>>(),
+            vec!["LENGTH", "MASS"]
+        );
+        assert!(registry.find_unit("hr", None).is_none());
+        assert!(UnitRegistry::new().find_unit("m", None).is_none());
+    }
+}
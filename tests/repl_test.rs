@@ -0,0 +1,161 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+#[derive(Clone)]
+struct TestCase {
+    // the lines entered in the interactive mode
+    input: &'static str,
+    expected_output: &'static str,
+    description: &'static str,
+}
+
+fn run_test(test_case: &TestCase) -> Result<(), String> {
+    let mut child = Command::new("cargo")
+        .arg("run")
+        .arg("--")
+        .arg("-i")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to spawn child process");
+
+    // write the lines to the stdin of the child process (and close it to end the session)
+    child
+        .stdin
+        .take()
+        .expect("Failed to open stdin")
+        .write_all(test_case.input.as_bytes())
+        .expect("Failed to write to stdin");
+
+    // get the stdout from the child process
+    let output = child.wait_with_output().expect("Failed to wait on child");
+    let stdout = String::from_utf8(output.stdout).expect("Failed to convert stdout to string");
+
+    // remove any trailing newlines and trim for clean comparison
+    let stdout_trimmed = stdout.trim();
+    let expected_trimmed = test_case.expected_output.trim();
+
+    if stdout_trimmed == expected_trimmed {
+        Ok(())
+    } else {
+        Err(format!(
+            "Test failed for input: '{}'\nExpected: '{}'\nGot: '{}'",
+            test_case.input, expected_trimmed, stdout_trimmed
+        ))
+    }
+}
+
+#[test]
+fn test_repl() {
+    let test_cases = vec![
+        TestCase {
+            input: "5 ft 10 in to cm\n1 gal = qt\n",
+            expected_output: "[LENGTH] 5 ft 10 in = 177.8 cm\n\
+            [VOLUME] 1 gal (Imperial) = 4 qt (Imperial)\n\
+            [VOLUME] 1 gal (Imperial) = 4.8 qt (US liquid)\n\
+            [VOLUME] 1 gal (US liquid) = 3.33 qt (Imperial)\n\
+            [VOLUME] 1 gal (US liquid) = 4 qt (US liquid)",
+            description: "Conversions",
+        },
+        TestCase {
+            input: "5 ft to m\nans to in\nans * 2 + 1 in = ft\n",
+            expected_output: "[LENGTH] 5 ft = 1.52 m\n\
+            [LENGTH] ans = 60 in\n\
+            [LENGTH] ans * 2 + 1 in = 10.08 ft",
+            description: "The last result as ans",
+        },
+        TestCase {
+            input:
+                "x = 3 ft 4 in\nx to cm\nspeed = 100 km / 2 hr\nspeed to m/s\ntwice = x * 2\n:vars\n",
+            expected_output: "x = [LENGTH] 3 ft 4 in\n\
+            [LENGTH] x = 101.6 cm\n\
+            speed = [SPEED] 100 km / 2 hr\n\
+            [SPEED] speed = 13.89 m/s\n\
+            twice = [LENGTH] x * 2\n\
+            x = [LENGTH] 3 ft 4 in\n\
+            ans = [SPEED] 13.89 m/s\n\
+            speed = [SPEED] 100 km / 2 hr\n\
+            twice = [LENGTH] x * 2",
+            description: "Variables",
+        },
+        TestCase {
+            input: "temp = 100 °C\ntemp to °F\n",
+            expected_output: "temp = [TEMPERATURE] 100 °C\n\
+            [TEMPERATURE] temp = 212 °F",
+            description: "A temperature variable",
+        },
+        TestCase {
+            input: "m = 5 ft\nz to m\n",
+            expected_output: "[ The unit 'm' can't be used as a variable name ]\n\
+            [ Missing source values ]",
+            description: "Invalid variables",
+        },
+        TestCase {
+            input: ":precision 4\n5 ft = m\n5 ft = m:1\n5 ft = m\n:precision\n:precision 20\n",
+            expected_output: "Precision: 4\n\
+            [LENGTH] 5 ft = 1.524 m\n\
+            [LENGTH] 5 ft = 1.5 m\n\
+            [LENGTH] 5 ft = 1.524 m\n\
+            Precision: 4\n\
+            Precision too high: 20 (using the max allowed precision of 14 instead)\n\
+            Precision: 14",
+            description: "Precision kept across lines",
+        },
+        TestCase {
+            input: ":precision 4\n5:30 h to d\n5:30 h = d:1\n",
+            expected_output: "Precision: 4\n\
+            [TIME] 5.5 hr = 0.2292 d\n\
+            [TIME] 5.5 hr = 0.2 d",
+            description: "Precision with a time value in the source",
+        },
+        TestCase {
+            input: ":units TEMPERATURE\n:units foo\n",
+            expected_output: "Supported units:\n\
+            -------------------------------------------\n\
+            | TEMPERATURE                             |\n\
+            -------------------------------------------\n\
+            | Kelvin                         | K      |\n\
+            | Celsius                        | °C     |\n\
+            | Fahrenheit                     | °F     |\n\
+            | Rankine                        | °R     |\n\
+            | Réaumur                        | °Ré    |\n\
+            | Rømer                          | °Rø    |\n\
+            | Delisle                        | °De    |\n\
+            | Newton                         | °N     |\n\
+            -------------------------------------------\n\
+            [ Unknown unit type: foo ]",
+            description: "Supported units of a unit type",
+        },
+        TestCase {
+            input: "1 m = cm\n:history\n!1\n!9\n",
+            expected_output: "[LENGTH] 1 m = 100 cm\n   \
+            1  1 m = cm\n   \
+            2  :history\n\
+            1 m = cm\n\
+            [LENGTH] 1 m = 100 cm\n\
+            [ Not in the history: !9 ]",
+            description: "History",
+        },
+        TestCase {
+            input: "1 m = cm\n:quit\n2 m = cm\n",
+            expected_output: "[LENGTH] 1 m = 100 cm",
+            description: "Quit",
+        },
+        TestCase {
+            input: ":foo\n5 ft\n",
+            expected_output: "[ Unknown command: :foo (type :help for help) ]\n\
+            [ Missing \"=\" or \"to\" between the source and the target unit ]",
+            description: "Errors",
+        },
+    ];
+
+    println!("Running {} test cases...", test_cases.len());
+    let mut failed = 0;
+    for test_case in test_cases.iter() {
+        if let Err(e) = run_test(test_case) {
+            println!("{} ... FAILED\n{}", test_case.description, e);
+            failed += 1;
+        }
+    }
+    assert_eq!(failed, 0, "{} test case(s) failed", failed);
+}
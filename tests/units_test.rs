@@ -0,0 +1,49 @@
+extern crate cu;
+
+#[cfg(test)]
+mod units_test {
+    use cu::UnitRegistry;
+
+    #[test]
+    fn test_no_ambiguous_units_within_unit_types() {
+        let mut collisions = Vec::new();
+        for (unit_type, units) in UnitRegistry::default().unit_types() {
+            for (i, unit) in units.iter().enumerate() {
+                for other in units.iter().skip(i + 1) {
+                    // abbreviations are matched case-sensitively first (e.g. "b" and "B"),
+                    // so they only collide if they're identical
+                    if unit.abbr == other.abbr {
+                        collisions.push(format!(
+                            "{}: '{}' ({}, {})",
+                            unit_type, unit.abbr, unit.name, other.name
+                        ));
+                    }
+                    // aliases are matched case-insensitively
+                    let other_names: Vec<String> = other
+                        .aliases
+                        .iter()
+                        .chain(std::iter::once(&other.abbr))
+                        .map(|a| a.to_lowercase())
+                        .collect();
+                    for alias in unit.aliases.iter() {
+                        if other_names.contains(&alias.to_lowercase()) {
+                            collisions.push(format!(
+                                "{}: '{}' ({}, {})",
+                                unit_type, alias, unit.name, other.name
+                            ));
+                        }
+                    }
+                    for alias in other.aliases.iter() {
+                        if unit.abbr.to_lowercase() == alias.to_lowercase() {
+                            collisions.push(format!(
+                                "{}: '{}' ({}, {})",
+                                unit_type, alias, unit.name, other.name
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        assert!(collisions.is_empty(), "ambiguous units: {:#?}", collisions);
+    }
+}
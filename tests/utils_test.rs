@@ -1,3 +1,5 @@
+#![allow(clippy::approx_constant)]
+
 extern crate cu;
 extern crate regex;
 
@@ -5,53 +7,148 @@ extern crate regex;
 mod utils_test {
     use cu::{
         units::MAX_PRECISION,
-        utils::{find_unit, format_number, parse_value_unit},
+        utils::{
+            format_number, format_value, parse_precision, parse_value_unit, split_value_unit_pairs,
+        },
+        ConvertError, UnitRegistry,
     };
 
     #[test]
     fn test_parse_value_unit() {
         // test regular number with unit
         let result = parse_value_unit("5ft");
-        assert!(result.is_some());
+        assert!(result.is_ok());
         let (value, unit) = result.unwrap();
         assert_eq!(value, 5.0);
         assert_eq!(unit, "ft");
 
         // test decimal number with unit
         let result = parse_value_unit("3.14m");
-        assert!(result.is_some());
+        assert!(result.is_ok());
         let (value, unit) = result.unwrap();
         assert_eq!(value, 3.14);
         assert_eq!(unit, "m");
 
         // test fraction with unit
         let result = parse_value_unit("1/2in");
-        assert!(result.is_some());
+        assert!(result.is_ok());
         let (value, unit) = result.unwrap();
         assert_eq!(value, 0.5);
         assert_eq!(unit, "in");
 
         // test negative number with unit
         let result = parse_value_unit("-10km");
-        assert!(result.is_some());
+        assert!(result.is_ok());
         let (value, unit) = result.unwrap();
         assert_eq!(value, -10.0);
         assert_eq!(unit, "km");
 
         // test unit with space
         let result = parse_value_unit("42 kg");
-        assert!(result.is_some());
+        assert!(result.is_ok());
         let (value, unit) = result.unwrap();
         assert_eq!(value, 42.0);
         assert_eq!(unit, "kg");
 
+        // test scientific notation
+        let result = parse_value_unit("6.02e23 eV");
+        assert!(result.is_ok());
+        let (value, unit) = result.unwrap();
+        assert_eq!(value, 6.02e23);
+        assert_eq!(unit, "eV");
+
+        // test scientific notation with a signed exponent
+        let result = parse_value_unit("1.5E-9 s");
+        assert!(result.is_ok());
+        let (value, unit) = result.unwrap();
+        assert_eq!(value, 1.5e-9);
+        assert_eq!(unit, "s");
+
+        // test explicit positive sign
+        let result = parse_value_unit("+3 m");
+        assert!(result.is_ok());
+        let (value, unit) = result.unwrap();
+        assert_eq!(value, 3.0);
+        assert_eq!(unit, "m");
+
+        // test leading decimal point
+        let result = parse_value_unit(".5km");
+        assert!(result.is_ok());
+        let (value, unit) = result.unwrap();
+        assert_eq!(value, 0.5);
+        assert_eq!(unit, "km");
+
+        // test fraction with exponents
+        let result = parse_value_unit("1e3/4 B");
+        assert!(result.is_ok());
+        let (value, unit) = result.unwrap();
+        assert_eq!(value, 250.0);
+        assert_eq!(unit, "B");
+
+        // test a unit starting with an "e" (not an exponent)
+        let result = parse_value_unit("2eV");
+        assert!(result.is_ok());
+        let (value, unit) = result.unwrap();
+        assert_eq!(value, 2.0);
+        assert_eq!(unit, "eV");
+
+        // test sexagesimal values (minutes and seconds, hours, minutes and seconds)
+        let result = parse_value_unit("5:30 min/km");
+        assert!(result.is_ok());
+        let (value, unit) = result.unwrap();
+        assert_eq!(value, 5.5);
+        assert_eq!(unit, "min/km");
+        let result = parse_value_unit("1:05:30hr");
+        assert!(result.is_ok());
+        let (value, unit) = result.unwrap();
+        assert!((value - 1.0916666666666666).abs() < 1e-12);
+        assert_eq!(unit, "hr");
+
         // test invalid input
         let result = parse_value_unit("abc");
-        assert!(result.is_none());
+        assert!(result.is_err());
 
         // test empty input
         let result = parse_value_unit("");
-        assert!(result.is_none());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_split_value_unit_pairs() {
+        assert_eq!(split_value_unit_pairs("5ft 10 in"), vec!["5ft", "10 in"]);
+        assert_eq!(
+            split_value_unit_pairs("6.02e23 eV 1.5E-9 J"),
+            vec!["6.02e23 eV", "1.5E-9 J"]
+        );
+        assert_eq!(split_value_unit_pairs("+3 m .5 ft"), vec!["+3 m", ".5 ft"]);
+        assert_eq!(split_value_unit_pairs("-5ft -10in"), vec!["-5ft", "-10in"]);
+        assert_eq!(
+            split_value_unit_pairs("1/2 mile 2m2"),
+            vec!["1/2 mile", "2m2"]
+        );
+        assert_eq!(
+            split_value_unit_pairs("5.6 L/100km 5:30 min/km"),
+            vec!["5.6 L/100km", "5:30 min/km"]
+        );
+    }
+
+    #[test]
+    fn test_parse_precision() {
+        assert_eq!(parse_precision("4"), Ok(4));
+        assert_eq!(parse_precision("0"), Ok(0));
+        assert_eq!(parse_precision("*"), Ok(MAX_PRECISION));
+        assert_eq!(
+            parse_precision("20"),
+            Err(ConvertError::PrecisionTooHigh("20".to_string()))
+        );
+        assert_eq!(
+            parse_precision("-1"),
+            Err(ConvertError::NegativePrecision("-1".to_string()))
+        );
+        assert_eq!(
+            parse_precision("x"),
+            Err(ConvertError::InvalidPrecision("x".to_string()))
+        );
     }
 
     #[test]
@@ -88,63 +185,202 @@ mod utils_test {
         assert_eq!(format_number(&0.000123, Some(5)), "0.00012");
     }
 
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(&5.0), "5");
+        assert_eq!(format_value(&-0.0625), "-0.0625");
+        assert_eq!(format_value(&0.0), "0");
+        assert_eq!(format_value(&6.02e23), "6.02e23");
+        assert_eq!(format_value(&1.5e-9), "1.5e-9");
+    }
+
     #[test]
     fn test_find_unit() {
+        let registry = UnitRegistry::default();
+
         // test valid unit with exact match
-        let result = find_unit("m", None);
+        let result = registry.find_unit("m", None);
         assert!(result.is_some());
-        let (unit_type, unit) = result.unwrap();
-        assert_eq!(unit_type, "LENGTH");
+        let unit = result.unwrap();
+        assert_eq!(unit.unit_type.as_deref(), Some("LENGTH"));
         assert_eq!(unit.name, "Meter");
 
         // test potentially conflicting unit abbreviations via case-sensitive match - #1
-        let result = find_unit("B", None);
+        let result = registry.find_unit("B", None);
         assert!(result.is_some());
-        let (unit_type, unit) = result.unwrap();
-        assert_eq!(unit_type, "DIGITAL STORAGE");
+        let unit = result.unwrap();
+        assert_eq!(unit.unit_type.as_deref(), Some("DIGITAL STORAGE"));
         assert_eq!(unit.name, "Byte");
 
         // test potentially conflicting unit abbreviations via case-sensitive match - #2
-        let result = find_unit("b", None);
+        let result = registry.find_unit("b", None);
         assert!(result.is_some());
-        let (unit_type, unit) = result.unwrap();
-        assert_eq!(unit_type, "DIGITAL STORAGE");
+        let unit = result.unwrap();
+        assert_eq!(unit.unit_type.as_deref(), Some("DIGITAL STORAGE"));
         assert_eq!(unit.name, "Bit");
 
         // test valid unit with case-insensitive match
-        let result = find_unit("KM", None);
+        let result = registry.find_unit("KM", None);
         assert!(result.is_some());
-        let (unit_type, unit) = result.unwrap();
-        assert_eq!(unit_type, "LENGTH");
+        let unit = result.unwrap();
+        assert_eq!(unit.unit_type.as_deref(), Some("LENGTH"));
         assert_eq!(unit.name, "Kilometer");
 
         // test valid unit with short alias
-        let result = find_unit("m2", None);
+        let result = registry.find_unit("m2", None);
         assert!(result.is_some());
-        let (unit_type, unit) = result.unwrap();
-        assert_eq!(unit_type, "AREA");
+        let unit = result.unwrap();
+        assert_eq!(unit.unit_type.as_deref(), Some("AREA"));
         assert_eq!(unit.abbr, "m²");
 
         // test valid unit with long alias
-        let result = find_unit("square meter", None);
+        let result = registry.find_unit("square meter", None);
         assert!(result.is_some());
-        let (unit_type, unit) = result.unwrap();
-        assert_eq!(unit_type, "AREA");
+        let unit = result.unwrap();
+        assert_eq!(unit.unit_type.as_deref(), Some("AREA"));
         assert_eq!(unit.abbr, "m²");
 
         // test unit with special characters
-        let result = find_unit("°C", None);
+        let result = registry.find_unit("°C", None);
         assert!(result.is_some());
-        let (unit_type, unit) = result.unwrap();
-        assert_eq!(unit_type, "TEMPERATURE");
+        let unit = result.unwrap();
+        assert_eq!(unit.unit_type.as_deref(), Some("TEMPERATURE"));
         assert_eq!(unit.name, "Celsius");
 
         // test invalid unit
-        let result = find_unit("invalid_unit", None);
+        let result = registry.find_unit("invalid_unit", None);
         assert!(result.is_none());
 
         // test empty string
-        let result = find_unit("", None);
+        let result = registry.find_unit("", None);
         assert!(result.is_none());
+
+        // test prefixed units
+        let result = registry.find_unit("kPa", None);
+        assert!(result.is_some());
+        let unit = result.unwrap();
+        assert_eq!(unit.unit_type.as_deref(), Some("PRESSURE"));
+        assert_eq!(unit.name, "Kilopascal");
+        assert_eq!(unit.abbr, "kPa");
+        assert_eq!(unit.ratios, vec![("".to_string(), 1000.0)]);
+
+        // test prefixed units with alternative prefix and unit symbols
+        let result = registry.find_unit("um", None);
+        assert!(result.is_some());
+        assert_eq!(result.unwrap().abbr, "μm");
+        let result = registry.find_unit("Mg", None);
+        assert!(result.is_some());
+        let unit = result.unwrap();
+        assert_eq!(unit.unit_type.as_deref(), Some("MASS"));
+        assert_eq!(unit.ratios, vec![("".to_string(), 1000.0)]);
+
+        // test IEC prefixes
+        let result = registry.find_unit("EiB", None);
+        assert!(result.is_some());
+        let unit = result.unwrap();
+        assert_eq!(unit.name, "Exbibyte");
+        // (8 bits per byte)
+        assert_eq!(
+            unit.ratios,
+            vec![("".to_string(), 8.0 * 1152921504606846976.0)]
+        );
+
+        // test prefixed unit names
+        let result = registry.find_unit("Kilopascals", None);
+        assert!(result.is_some());
+        assert_eq!(result.unwrap().abbr, "kPa");
+
+        // test case-sensitive priority of prefixed units over case-insensitive matches
+        let result = registry.find_unit("mHz", None);
+        assert!(result.is_some());
+        assert_eq!(result.unwrap().name, "Millihertz");
+        let result = registry.find_unit("MHz", None);
+        assert!(result.is_some());
+        assert_eq!(result.unwrap().name, "Megahertz");
+        let result = registry.find_unit("Eb", None);
+        assert!(result.is_some());
+        assert_eq!(result.unwrap().name, "Exabit");
+
+        // test priority of exact aliases over prefixed units ("am" is not an attometer)
+        let result = registry.find_unit("am", None);
+        assert!(result.is_some());
+        assert_eq!(result.unwrap().abbr, "arcmin");
+
+        // test prefixes a unit doesn't accept
+        assert!(registry.find_unit("cB", None).is_none());
+        assert!(registry.find_unit("Kim", None).is_none());
+        assert!(registry.find_unit("kK", None).is_none());
+    }
+
+    #[test]
+    fn test_find_units() {
+        let registry = UnitRegistry::default();
+
+        // test ambiguous units (at most one per unit type, in the unit types order)
+        let names = |unit_str: &str| -> Vec<(Option<String>, String)> {
+            registry
+                .find_units(unit_str, None)
+                .into_iter()
+                .map(|u| (u.unit_type, u.name))
+                .collect()
+        };
+        assert_eq!(
+            names("m"),
+            vec![
+                (Some("LENGTH".to_string()), "Meter".to_string()),
+                (Some("TIME".to_string()), "Minute".to_string())
+            ]
+        );
+        assert_eq!(
+            names("c"),
+            vec![
+                (Some("ELECTRIC CHARGE".to_string()), "Coulomb".to_string()),
+                (Some("SPEED".to_string()), "Speed of Light".to_string()),
+                (Some("TEMPERATURE".to_string()), "Celsius".to_string()),
+                (Some("TIME".to_string()), "Century".to_string()),
+                (
+                    Some("VOLUME".to_string()),
+                    "Cup • Imperial / US legal".to_string()
+                )
+            ]
+        );
+        assert_eq!(
+            names("d"),
+            vec![
+                (Some("PLANE ANGLE".to_string()), "Degree".to_string()),
+                (Some("TIME".to_string()), "Day".to_string())
+            ]
+        );
+
+        // test unambiguous units
+        assert_eq!(
+            names("ft"),
+            vec![(Some("LENGTH".to_string()), "Foot".to_string())]
+        );
+        assert_eq!(
+            names("kn"),
+            vec![(Some("SPEED".to_string()), "Knot".to_string())]
+        );
+
+        // test the exact (case-sensitive) match of ambiguous units
+        let name = |unit_str: &str| registry.find_unit(unit_str, None).map(|u| u.name);
+        assert_eq!(name("A"), Some("Ampere".to_string()));
+        assert_eq!(name("a"), Some("Acre".to_string()));
+        assert_eq!(name("h"), Some("Hour".to_string()));
+        assert_eq!(name("H"), Some("Henry".to_string()));
+        assert_eq!(name("f"), Some("Fahrenheit".to_string()));
+
+        // test units of a given unit type
+        assert_eq!(
+            registry
+                .find_units("m", Some("TIME"))
+                .into_iter()
+                .map(|u| u.name)
+                .collect::<Vec<String>>(),
+            vec!["Minute".to_string()]
+        );
+
+        // test unknown units
+        assert!(registry.find_units("invalid_unit", None).is_empty());
     }
 }